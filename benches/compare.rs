@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use h_version::{cmp_raw, Version};
+
+fn versions() -> Vec<String> {
+    (0..1000u64)
+        .map(|i| format!("{}.{}.{}", i % 7, (i * 31) % 100, (i * 17) % 1000))
+        .collect()
+}
+
+fn compare(c: &mut Criterion) {
+    let strings = versions();
+    let parsed: Vec<Version> = strings.iter().map(|s| Version::parse(s)).collect();
+    c.bench_function("sort numeric versions", |b| {
+        b.iter(|| {
+            let mut versions = parsed.clone();
+            versions.sort();
+            black_box(versions)
+        })
+    });
+    c.bench_function("sort numeric versions by key", |b| {
+        b.iter(|| {
+            let mut versions = parsed.clone();
+            versions.sort_by_cached_key(Version::sort_key);
+            black_box(versions)
+        })
+    });
+    c.bench_function("sort numeric version strings with cmp_raw", |b| {
+        b.iter(|| {
+            let mut versions: Vec<&str> = strings.iter().map(String::as_str).collect();
+            versions.sort_by(|a, b| cmp_raw(a, b));
+            black_box(versions)
+        })
+    });
+    let (a, b) = (Version::parse("1.2.3-rc.1"), Version::parse("1.2.3-rc.2"));
+    c.bench_function("compare pre-releases", |bench| bench.iter(|| black_box(&a).cmp(black_box(&b))));
+}
+
+criterion_group!(benches, compare);
+criterion_main!(benches);
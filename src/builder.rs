@@ -0,0 +1,55 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::version::has_separator;
+use crate::{ParseVersionError, Version};
+
+/// builds a [`Version`] part by part, see [`Version::builder`].
+/// # Example
+/// ```
+/// use h_version::Version;
+/// let version = Version::builder().epoch(1).component("2").component("3").pre_release("rc").build().unwrap();
+/// assert_eq!(version.to_string(), "1:2.3-rc");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VersionBuilder {
+    epoch: Option<u64>,
+    components: Vec<String>,
+    pre_release: Option<String>,
+    build_metadata: Option<String>,
+}
+impl VersionBuilder {
+    /// sets the epoch.
+    pub fn epoch(mut self, epoch: u64) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    /// adds a main component after the ones already added.
+    pub fn component(mut self, component: impl Into<String>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+    /// sets the pre-release, an empty one is none.
+    pub fn pre_release(mut self, pre_release: impl Into<String>) -> Self {
+        self.pre_release = Some(pre_release.into());
+        self
+    }
+    /// sets the build metadata, an empty one is none.
+    pub fn build_metadata(mut self, build_metadata: impl Into<String>) -> Self {
+        self.build_metadata = Some(build_metadata.into());
+        self
+    }
+    /// makes the version. there must be at least one component, and none of them can be empty or
+    /// contain a separator (`.`, `_`, `-`, `~`, `+` or `:`).
+    pub fn build(self) -> Result<Version, ParseVersionError> {
+        if self.components.is_empty() {
+            return Err(ParseVersionError::NoComponents);
+        }
+        if self.components.iter().any(|component| component.is_empty()) {
+            return Err(ParseVersionError::EmptyComponent);
+        }
+        if let Some(component) = self.components.iter().find(|component| has_separator(component)) {
+            return Err(ParseVersionError::SeparatorInComponent(component.clone()));
+        }
+        Ok(Version::new(self.epoch, self.components, self.pre_release, self.build_metadata))
+    }
+}
@@ -0,0 +1,23 @@
+/// the most significant part in which two versions differ, see [`Version::diff`](crate::Version::diff).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionDiff {
+    /// the epochs differ (e.g. "1:1.0.0" and "2:1.0.0")
+    Epoch,
+    /// the first components differ (e.g. "1.2.3" and "2.0.0")
+    Major,
+    /// the second components differ (e.g. "1.2.3" and "1.3.0")
+    Minor,
+    /// the third components differ (e.g. "1.2.3" and "1.2.4")
+    Patch,
+    /// a component after the third one differs (e.g. "6.1.7601.1" and "6.1.7601.2")
+    Extra,
+    /// only the pre-releases differ (e.g. "1.0.0-alpha" and "1.0.0")
+    PreRelease,
+    /// only the Debian revisions differ (e.g. "1.2.3-1" and "1.2.3-2" parsed with
+    /// [`Version::parse_debian`](crate::Version::parse_debian))
+    Revision,
+    /// only the build metadata differs (e.g. "1.0.0+001" and "1.0.0+002")
+    Build,
+    /// the versions are equal
+    Equal,
+}
@@ -0,0 +1,127 @@
+use core::fmt::Display;
+use alloc::string::String;
+use crate::VersionScheme;
+
+/// the reason a version string could not be parsed.
+///
+/// # Example
+/// ```
+/// use h_version::{ParseVersionError, Version};
+/// let result = "".parse::<Version>();
+/// assert_eq!(result, Err(ParseVersionError::Empty));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVersionError {
+    /// the input was an empty string
+    Empty,
+    /// the part before `:` is not a valid epoch (e.g. "bad:1.2.3"), or does not fit in a `u64`
+    BadEpoch(String),
+    /// there are no main components at all (e.g. "1:" or "-alpha")
+    NoComponents,
+    /// one of the main components is empty (e.g. "1..3")
+    EmptyComponent,
+    /// [`VersionBuilder::component`](crate::VersionBuilder::component) got a component with a
+    /// separator in it (e.g. "1.2")
+    SeparatorInComponent(String),
+    /// [`Version::parse_semver`](crate::Version::parse_semver) got an epoch (e.g. "1:1.2.3")
+    HasEpoch,
+    /// [`Version::parse_semver`](crate::Version::parse_semver) got a number of main components
+    /// other than three (e.g. "1.2")
+    WrongComponentCount(usize),
+    /// [`Version::parse_semver`](crate::Version::parse_semver) got a non-numeric main component
+    /// (e.g. "1.x.3")
+    NonNumericComponent(String),
+    /// [`Version::parse_semver`](crate::Version::parse_semver) got a number with leading zeros
+    /// (e.g. "01.2.3")
+    LeadingZero(String),
+    /// the version is not valid SemVer: a pre-release or build identifier is empty or has a
+    /// character other than `[0-9A-Za-z-]` (e.g. "rc_1" in "1.2.3-rc_1"), the components are not
+    /// written as SemVer (e.g. "v1.2.3" or "1_2_3"), the pre-release follows `~` (e.g. "~rc"), there
+    /// is a Debian revision (e.g. "-1", see [`Version::parse_debian`](crate::Version::parse_debian))
+    /// or a number does not fit in a `u64`
+    NotSemVer(String),
+    /// a comparator of a [`VersionReq`](crate::VersionReq) is invalid (e.g. ">=" or "^x.1"), or a
+    /// [`VersionRange`](crate::VersionRange) has no ".."
+    BadRequirement(String),
+}
+impl Display for ParseVersionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseVersionError::Empty => write!(f, "version string is empty"),
+            ParseVersionError::BadEpoch(epoch) => write!(f, "invalid epoch \"{epoch}\""),
+            ParseVersionError::NoComponents => write!(f, "version has no components"),
+            ParseVersionError::EmptyComponent => write!(f, "version has an empty component"),
+            ParseVersionError::SeparatorInComponent(component) => {
+                write!(f, "component \"{component}\" contains a separator")
+            }
+            ParseVersionError::HasEpoch => write!(f, "SemVer versions can not have an epoch"),
+            ParseVersionError::WrongComponentCount(count) => {
+                write!(f, "SemVer versions must have 3 components, found {count}")
+            }
+            ParseVersionError::NonNumericComponent(component) => {
+                write!(f, "component \"{component}\" is not numeric")
+            }
+            ParseVersionError::LeadingZero(number) => write!(f, "number \"{number}\" has a leading zero"),
+            ParseVersionError::NotSemVer(part) => write!(f, "\"{part}\" is not valid in a SemVer version"),
+            ParseVersionError::BadRequirement(comparator) => write!(f, "invalid requirement \"{comparator}\""),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVersionError {}
+
+/// the reason two versions could not be compared, see [`Version::try_cmp`](crate::Version::try_cmp).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompareError {
+    /// one of the versions has a main component that is not a number (e.g. "sjf" in "2.sjf.5djf")
+    NonNumericComponent(String),
+    /// the versions have different schemes, see
+    /// [`Version::try_cmp_same_scheme`](crate::Version::try_cmp_same_scheme)
+    DifferentSchemes(VersionScheme, VersionScheme),
+}
+impl Display for CompareError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CompareError::NonNumericComponent(component) => {
+                write!(f, "component \"{component}\" is not numeric")
+            }
+            CompareError::DifferentSchemes(scheme, other_scheme) => {
+                write!(f, "can not compare versions of different schemes ({scheme} and {other_scheme})")
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for CompareError {}
+
+/// something [`Version::parse_verbose`](crate::Version::parse_verbose) changed or dropped to
+/// accept the input.
+///
+/// # Example
+/// ```
+/// use h_version::{ParseWarning, Version};
+/// let (version, warnings) = Version::parse_verbose("v1..3");
+/// assert_eq!(version.to_string(), "1.3");
+/// assert_eq!(warnings, [ParseWarning::PrefixStripped('v'), ParseWarning::EmptyComponentsDropped(1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// the prefix of a git tag was stripped (e.g. the "v" of "v1.2.3")
+    PrefixStripped(char),
+    /// the part before `:` is not a valid epoch and was dropped (e.g. "bad" in "bad:1.2.3")
+    EpochDropped(String),
+    /// this many empty main components were dropped (e.g. one in "1..3")
+    EmptyComponentsDropped(usize),
+    /// the input is not a version at all, so the [`Default`] version was returned instead
+    Defaulted(ParseVersionError),
+}
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseWarning::PrefixStripped(prefix) => write!(f, "prefix \"{prefix}\" was stripped"),
+            ParseWarning::EpochDropped(epoch) => write!(f, "invalid epoch \"{epoch}\" was dropped"),
+            ParseWarning::EmptyComponentsDropped(count) => write!(f, "{count} empty component(s) were dropped"),
+            ParseWarning::Defaulted(error) => write!(f, "{error}, the default version was used"),
+        }
+    }
+}
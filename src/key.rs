@@ -0,0 +1,147 @@
+use core::cmp::Ordering;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::version::{is_number, is_snapshot, Component};
+
+/// a pre-computed sort key of a [`Version`](crate::Version), see
+/// [`Version::sort_key`](crate::Version::sort_key).
+///
+/// keys are ordered exactly like the versions they come from, but comparing them does not parse
+/// anything, so they are cheap to compare many times (e.g. when sorting a long list).
+/// # Example
+/// ```
+/// use h_version::Version;
+/// let mut versions = vec![Version::parse("1.10.0"), Version::parse("1.2.0"), Version::parse("1.2.0-rc")];
+/// versions.sort_by_cached_key(Version::sort_key);
+/// assert_eq!(versions, [Version::parse("1.2.0-rc"), Version::parse("1.2.0"), Version::parse("1.10.0")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VersionKey {
+    epoch: u64, // a missing epoch is 0
+    components: Vec<Part>, // without trailing zeros, a missing component is 0
+    pre_release: PreRelease,
+    revision: Option<u64>,
+    build_metadata: Option<String>,
+}
+/// a main component, the variants are in order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Part {
+    Dev(Number),  // "dev4" (PEP 440)
+    Num(Number),  // "4"
+    Post(Number), // "post4" (PEP 440)
+    Str(String),  // "x"
+}
+/// a pre-release, the variants are in order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum PreRelease {
+    Tilde(Vec<Identifier>), // "1.0.0~beta"
+    Dash(Vec<Identifier>),  // "1.0.0-alpha"
+    None,                   // "1.0.0"
+}
+/// a pre-release identifier, the variants are in order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Identifier {
+    Snapshot, // "SNAPSHOT", the lowest
+    Num(Number),
+    Str(String), // lowercase
+}
+/// a number of any size, the variants are in order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Number {
+    Small(u128),        // a number that fits in a `u128`
+    Big(usize, String), // the number of digits, and the digits without leading zeros
+}
+const ZERO: Part = Part::Num(Number::Small(0));
+
+impl VersionKey {
+    pub(crate) fn new(
+        epoch: Option<u64>,
+        components: &[String],
+        pre_release: Option<&str>,
+        tilde: bool,
+        revision: Option<u64>,
+        build_metadata: Option<&str>,
+    ) -> Self {
+        let mut components: Vec<Part> = components.iter().map(|component| Part::new(component)).collect();
+        while components.last() == Some(&ZERO) {
+            components.pop();
+        }
+        let pre_release = match pre_release {
+            Some(pre_release) => {
+                let identifiers = pre_release.split('.').map(Identifier::new).collect();
+                if tilde { PreRelease::Tilde(identifiers) } else { PreRelease::Dash(identifiers) }
+            }
+            None => PreRelease::None,
+        };
+        VersionKey {
+            epoch: epoch.unwrap_or(0),
+            components,
+            pre_release,
+            revision,
+            build_metadata: build_metadata.map(|s| s.to_string()),
+        }
+    }
+    // like `Version::cmp_components`, a missing component counts as 0
+    fn cmp_components(&self, other: &VersionKey) -> Ordering {
+        let len = self.components.len().max(other.components.len());
+        (0..len)
+            .map(|i| {
+                let a = self.components.get(i).unwrap_or(&ZERO);
+                let b = other.components.get(i).unwrap_or(&ZERO);
+                a.cmp(b)
+            })
+            .find(|cmp| cmp.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+impl PartialOrd for VersionKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for VersionKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| self.cmp_components(other))
+            .then_with(|| self.pre_release.cmp(&other.pre_release))
+            .then_with(|| self.revision.cmp(&other.revision))
+            .then_with(|| self.build_metadata.cmp(&other.build_metadata))
+    }
+}
+impl Part {
+    fn new(component: &str) -> Self {
+        match Component::new(component) {
+            Component::Dev(number) => Part::Dev(Number::new(number)),
+            Component::Numeric(number) => Part::Num(Number::Small(number)),
+            Component::BigNumeric(number) => Part::Num(Number::new(number)),
+            Component::Post(number) => Part::Post(Number::new(number)),
+            Component::Alpha(component) => Part::Str(component.to_string()),
+        }
+    }
+}
+impl Identifier {
+    fn new(identifier: &str) -> Self {
+        if is_snapshot(identifier) {
+            Identifier::Snapshot
+        } else if is_number(identifier) {
+            Identifier::Num(Number::new(identifier))
+        } else {
+            // lowercased like `cmp_identifier` does, char by char
+            Identifier::Str(identifier.chars().flat_map(char::to_lowercase).collect())
+        }
+    }
+}
+impl Number {
+    /// `digits` must be ASCII digits only, or empty for 0
+    fn new(digits: &str) -> Self {
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            return Number::Small(0);
+        }
+        match digits.parse() {
+            Ok(number) => Number::Small(number),
+            Err(_) => Number::Big(digits.len(), digits.to_string()),
+        }
+    }
+}
@@ -1,14 +1,47 @@
 //! # H-Version
 //!
 //! 'H-Version' is a version comparing library that meant to be used for H foundation projects
+//!
+//! # Features
+//! - `std` (default): the standard library, and `std::error::Error` for the error types. without
+//!   it, the library is `no_std` and only needs `alloc`. the command line tool always needs it.
+//! - `serde`: `Serialize` and `Deserialize` for [`Version`], as a version string.
+//! - `chrono`: [`Version::build_timestamp`], to read build metadata like "+20230301144500".
+//! - `semver-compat`: conversions between [`Version`] and `semver::Version` of the `semver` crate.
+
+// CI: besides the default build, `cargo build --no-default-features` must keep compiling,
+// so use `core` and `alloc` paths in the library instead of `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+extern crate alloc;
 
+mod builder;
+mod diff;
+mod error;
+mod key;
+mod parser;
+mod range;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "semver-compat")]
+mod semver_impl;
+mod req;
+mod scheme;
 mod version;
-pub use version::Version;
+pub use builder::VersionBuilder;
+pub use diff::VersionDiff;
+pub use error::{CompareError, ParseVersionError, ParseWarning};
+pub use key::VersionKey;
+pub use range::VersionRange;
+pub use req::VersionReq;
+pub use scheme::VersionScheme;
+pub use version::{cmp_raw, Version};
 #[cfg(test)]
 mod tests {
-    use std::cmp::Ordering::Equal;
+    use std::cmp::Ordering::{Equal, Greater, Less};
     use crate::*;
+    use std::collections::HashSet;
     #[test]
+    #[allow(clippy::bool_assert_comparison, clippy::eq_op)]
     fn version_test() {
         let v1 = Version::parse("1.2.3-alpha+001");
         let v2 = Version::parse("1.2.3-beta+002");
@@ -34,27 +67,31 @@ mod tests {
         let v1_display = format!("{}", v1);
         assert_eq!(v1_display, "1.2.3-alpha+001");
         let v1_debug = format!("{:?}", v1);
-        assert_eq!(v1_debug, "epoch:0 components:[\"1\", \"2\", \"3\"] pre_release:alpha build_metadata:0");
+        assert_eq!(v1_debug, "epoch:0 components:[\"1\", \"2\", \"3\"] pre_release:alpha build_metadata:001");
 
         let v2_display = format!("{}", v2);
         assert_eq!(v2_display, "2023.03.01");
         let v2_debug = format!("{:?}", v2);
-        assert_eq!(v2_debug, "epoch:0 components:[\"2023\", \"03\", \"01\"] pre_release: build_metadata:0");
+        assert_eq!(v2_debug, "epoch:0 components:[\"2023\", \"03\", \"01\"] pre_release: build_metadata:");
 
         let v3_display = format!("{}", v3);
         assert_eq!(v3_display, "2.sjf.5djf");
         let v3_debug = format!("{:?}", v3);
-        assert_eq!(v3_debug, "epoch:0 components:[\"2\", \"sjf\", \"5djf\"] pre_release: build_metadata:0");
+        assert_eq!(v3_debug, "epoch:0 components:[\"2\", \"sjf\", \"5djf\"] pre_release: build_metadata:");
 
         let v4_display = format!("{}", v4);
         assert_eq!(v4_display, "1:2.3.4");
         let v4_debug = format!("{:?}", v4);
-        assert_eq!(v4_debug, "epoch:1 components:[\"2\", \"3\", \"4\"] pre_release: build_metadata:1");
+        assert_eq!(v4_debug, "epoch:1 components:[\"2\", \"3\", \"4\"] pre_release: build_metadata:");
 
         let v5_display = format!("{}", v5);
         assert_eq!(v5_display, "1.0.0-SNAPSHOT");
         let v5_debug = format!("{:?}", v5);
-        assert_eq!(v5_debug, "epoch:0 components:[\"1\", \"0\", \"0\"] pre_release:SNAPSHOT build_metadata:0");
+        assert_eq!(v5_debug, "epoch:0 components:[\"1\", \"0\", \"0\"] pre_release:SNAPSHOT build_metadata:");
+
+        let v6_debug = format!("{:?}", Version::parse("1.0~rc"));
+        assert_eq!(v6_debug, "epoch:0 components:[\"1\", \"0\"] pre_release:rc build_metadata: tilde:true");
+        assert_ne!(v6_debug, format!("{:?}", Version::parse("1.0-rc")));
     }
     #[test]
     fn test_of_additional_zero(){
@@ -62,4 +99,1432 @@ mod tests {
         let v2 = Version::parse("1.2.3.0");
         assert_eq!(v1.cmp(&v2), Equal);
     }
+    #[test]
+    fn from_str() {
+        let v1: Version = "1:2.3.4-rc+5".parse().unwrap();
+        assert_eq!(v1, Version::parse("1:2.3.4-rc+5"));
+        assert_eq!("".parse::<Version>(), Err(ParseVersionError::Empty));
+        assert_eq!("bad:1.2.3".parse::<Version>(), Err(ParseVersionError::BadEpoch("bad".to_string())));
+        assert_eq!("1..3".parse::<Version>(), Err(ParseVersionError::EmptyComponent));
+        // the infallible parse falls back to the default version
+        assert_eq!(Version::parse("bad:1.2.3"), Version::default());
+    }
+    #[test]
+    fn try_from() {
+        assert_eq!(Version::try_from("1:2.3.4"), Ok(Version::parse("1:2.3.4")));
+        assert_eq!(Version::try_from("2.sjf.5djf".to_string()), Ok(Version::parse("2.sjf.5djf")));
+        assert_eq!(Version::try_from(""), Err(ParseVersionError::Empty));
+        assert_eq!(Version::try_from("1a:2.3.4"), Err(ParseVersionError::BadEpoch("1a".to_string())));
+        assert_eq!(Version::try_from("1:"), Err(ParseVersionError::NoComponents));
+        assert_eq!(Version::try_from("-alpha".to_string()), Err(ParseVersionError::NoComponents));
+    }
+    #[test]
+    fn trailing_zero_components() {
+        assert_eq!(Version::parse("1.2").cmp(&Version::parse("1.2.0")), Equal);
+        assert_eq!(Version::parse("1.2").cmp(&Version::parse("1.2.1")), Less);
+        assert_eq!(Version::parse("1.2.1").cmp(&Version::parse("1.2")), Greater);
+        assert_eq!(Version::parse("1.0.0").cmp(&Version::parse("1")), Equal);
+        assert_eq!(Version::parse("1.2").cmp(&Version::parse("1.2.3")), Less);
+    }
+    #[test]
+    fn precedence_ignores_build_metadata() {
+        let v1 = Version::parse("1.0.0+001");
+        let v2 = Version::parse("1.0.0+002");
+        assert_eq!(v1.cmp_precedence(&v2), Equal);
+        assert_ne!(v1, v2);
+        let v3 = Version::parse("1.0.0-rc+exp.sha.5114f85");
+        let v4 = Version::parse("1.0.0-rc");
+        assert_eq!(v3.cmp_precedence(&v4), Equal);
+        assert_eq!(Version::parse("1.0.0-rc+5").cmp_precedence(&Version::parse("1.0.0+1")), Less);
+    }
+    #[test]
+    fn pre_release_identifiers_ordering() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+        for pair in versions.windows(2) {
+            assert!(Version::parse(pair[0]) < Version::parse(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+        assert!(Version::parse("1.0.0-alpha.2") < Version::parse("1.0.0-alpha.10"));
+    }
+    #[test]
+    fn constructors() {
+        let v1 = Version::new(Some(1), vec!["2".to_string(), "3".to_string()], None, Some("5".to_string()));
+        assert_eq!(v1, Version::parse("1:2.3+5"));
+        assert_eq!(Version::from_numbers(1, 2, 3), Version::parse("1.2.3"));
+    }
+    #[test]
+    fn clone() {
+        let v1 = Version::parse("1:2.3.4-rc+5");
+        let mut v2 = v1.clone();
+        assert_eq!(v1, v2);
+        v2.components[0] = "9".to_string();
+        assert_eq!(v1.components, ["2", "3", "4"]);
+        assert_ne!(v1, v2);
+    }
+    #[test]
+    fn hash() {
+        let mut set = HashSet::new();
+        set.insert(Version::parse("1.0.0"));
+        set.insert(Version::parse("1.0.0"));
+        assert_eq!(set.len(), 1);
+        set.insert(Version::parse("1.0.0+001"));
+        assert_eq!(set.len(), 2);
+    }
+    #[test]
+    fn major_minor_patch() {
+        let v1 = Version::parse("1.2.3");
+        assert_eq!((v1.major(), v1.minor(), v1.patch()), (Some(1), Some(2), Some(3)));
+        let v2 = Version::parse("2023.03.01");
+        assert_eq!((v2.major(), v2.minor(), v2.patch()), (Some(2023), Some(3), Some(1)));
+        let v3 = Version::parse("2.sjf.5djf");
+        assert_eq!((v3.major(), v3.minor(), v3.patch()), (Some(2), None, None));
+        let v4 = Version::parse("7");
+        assert_eq!((v4.major(), v4.minor(), v4.patch()), (Some(7), None, None));
+    }
+    #[test]
+    fn bump() {
+        let v1 = Version::parse("1:1.2.3-alpha+001");
+        assert_eq!(v1.bump_major(), Some(Version::parse("1:2.0.0")));
+        assert_eq!(v1.bump_minor(), Some(Version::parse("1:1.3.0")));
+        assert_eq!(v1.bump_patch(), Some(Version::parse("1:1.2.4")));
+        let v2 = Version::parse("1");
+        assert_eq!(v2.bump_patch(), Some(Version::parse("1.0.1")));
+        let v3 = Version::parse("2.sjf.5djf");
+        assert_eq!(v3.bump_major(), Some(Version::parse("3.0.0")));
+        assert_eq!(v3.bump_minor(), None);
+        assert_eq!(v3.bump_patch(), None);
+    }
+    #[test]
+    fn prerelease() {
+        assert!(Version::parse("1.0.0-alpha").is_prerelease());
+        assert!(!Version::parse("1.0.0-alpha").is_stable());
+        assert!(!Version::parse("1.0.0").is_prerelease());
+        assert!(Version::parse("1.0.0+001").is_stable());
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let v1 = Version::parse("1:2.3.4-rc+5");
+        let json = serde_json::to_string(&v1).unwrap();
+        assert_eq!(json, "\"1:2.3.4-rc+5\"");
+        let v2: Version = serde_json::from_str(&json).unwrap();
+        assert_eq!(v1, v2);
+        let error = serde_json::from_str::<Version>("\"bad:1.2.3\"").unwrap_err();
+        assert!(error.to_string().contains("invalid epoch"));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_debian_revision() {
+        // the revision is deserialized as a pre-release, pinned in `parse_debian`'s docs
+        let v1 = Version::parse_debian("1.2.3-1").unwrap();
+        let json = serde_json::to_string(&v1).unwrap();
+        assert_eq!(json, "\"1.2.3-1\"");
+        let v2: Version = serde_json::from_str(&json).unwrap();
+        assert_eq!((v2.pre_release.as_deref(), v2.revision()), (Some("1"), None));
+        assert!(v2 < Version::parse("1.2.3"));
+        let v3 = Version::parse_debian(&serde_json::from_str::<String>(&json).unwrap()).unwrap();
+        assert_eq!(v3, v1);
+    }
+    #[test]
+    fn version_req() {
+        let matches = |req: &str, version: &str| VersionReq::parse(req).unwrap().matches(&Version::parse(version));
+        assert!(matches("^1.2.3", "1.5.0"));
+        assert!(matches("^1.2.3", "1.2.3"));
+        assert!(!matches("^1.2.3", "2.0.0"));
+        assert!(!matches("^1.2.3", "1.2.2"));
+        assert!(matches("^0.2.3", "0.2.9"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+        assert!(matches("1.2", "1.9.0"));
+        assert!(matches("~1.2.3", "1.2.9"));
+        assert!(!matches("~1.2.3", "1.3.0"));
+        assert!(matches("~1", "1.9.0"));
+        assert!(matches(">=1.2.0, <2.0.0", "1.9.9"));
+        assert!(!matches(">=1.2.0, <2.0.0", "2.0.0"));
+        assert!(matches("=1.2.3", "1.2.3+build"));
+        assert!(matches("=1.2", "1.2.7"));
+        assert!(!matches("=1.2", "1.3.0"));
+        assert!(matches(">1.2", "1.3.0"));
+        assert!(!matches(">1.2", "1.2.9"));
+        assert!(matches("<=1.2", "1.2.9"));
+        assert!(!matches("<=1.2", "1.3.0"));
+        assert_eq!(VersionReq::parse(">=1.0.0,"), Err(ParseVersionError::BadRequirement("".to_string())));
+        assert_eq!(VersionReq::parse("^x.1"), Err(ParseVersionError::BadRequirement("^x.1".to_string())));
+        assert_eq!(VersionReq::parse(">=1..2"), Err(ParseVersionError::EmptyComponent));
+        for req in ["1.*", "^1.x", ">=1.x", ">=1.0 <2.0", "~1.2.3a"] {
+            assert_eq!(VersionReq::parse(req), Err(ParseVersionError::BadRequirement(req.to_string())), "{req}");
+        }
+        assert_eq!(
+            VersionReq::parse(">=1.0, <2.x"),
+            Err(ParseVersionError::BadRequirement("<2.x".to_string()))
+        );
+    }
+    #[test]
+    fn empty_components() {
+        assert_eq!("1..3".parse::<Version>(), Err(ParseVersionError::EmptyComponent));
+        assert_eq!("1.".parse::<Version>(), Err(ParseVersionError::EmptyComponent));
+        assert_eq!(".1".parse::<Version>(), Err(ParseVersionError::EmptyComponent));
+        assert_eq!(Version::parse("1..3").components, ["1", "3"]);
+        assert_eq!(Version::parse("1.").components, ["1"]);
+        assert_eq!(Version::parse(".1").components, ["1"]);
+        assert_eq!(Version::parse(".."), Version::default());
+    }
+    #[test]
+    fn missing_epoch_is_zero() {
+        assert_eq!(Version::parse("0:1.2.3"), Version::parse("1.2.3"));
+        assert_eq!(Version::parse("0:1.2.3").cmp(&Version::parse("1.2.3")), Equal);
+        assert!(Version::parse("1:1.0.0") > Version::parse("1.9.9"));
+        let mut set = HashSet::new();
+        set.insert(Version::parse("0:1.2.3"));
+        set.insert(Version::parse("1.2.3"));
+        assert_eq!(set.len(), 1);
+    }
+    #[test]
+    fn components_iteration() {
+        let v1 = Version::parse("1.2.3");
+        assert_eq!(v1.iter_components().collect::<Vec<_>>(), ["1", "2", "3"]);
+        assert_eq!(v1.numeric_components(), [Some(1), Some(2), Some(3)]);
+        let v2 = Version::parse("2.sjf.5djf");
+        assert_eq!(v2.numeric_components(), [Some(2), None, None]);
+        let first_difference = v1.iter_components().zip(v2.iter_components()).position(|(a, b)| a != b);
+        assert_eq!(first_difference, Some(0));
+    }
+    #[test]
+    fn total_ordering() {
+        let expected = [
+            "0.9.9",
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-beta+001",
+            "1.0.0-beta+002",
+            "1.0.0",
+            "1.0.0+001",
+            "1.0.1",
+            "1.2",
+            "1.10.0",
+            "2023.03.01",
+            "1:0.1.0",
+        ];
+        let mut versions: Vec<Version> = ["1.0.0+001", "1:0.1.0", "1.0.0-beta+002", "1.2", "0.9.9", "2023.03.01", "1.0.0", "1.0.0-alpha.1", "1.10.0", "1.0.0-beta+001", "1.0.1", "1.0.0-alpha"]
+            .into_iter()
+            .map(Version::parse)
+            .collect();
+        versions.sort();
+        let sorted: Vec<String> = versions.iter().map(Version::to_string).collect();
+        assert_eq!(sorted, expected);
+    }
+    #[test]
+    fn strict_semver() {
+        assert_eq!(Version::parse_semver("1.2.3"), Ok(Version::parse("1.2.3")));
+        assert_eq!(Version::parse_semver("1.2.3-rc.1+build.5"), Ok(Version::parse("1.2.3-rc.1+build.5")));
+        assert_eq!(Version::parse_semver("1.2"), Err(ParseVersionError::WrongComponentCount(2)));
+        assert_eq!(Version::parse_semver("1.2.3.4"), Err(ParseVersionError::WrongComponentCount(4)));
+        assert_eq!(Version::parse_semver("01.2.3"), Err(ParseVersionError::LeadingZero("01".to_string())));
+        assert_eq!(Version::parse_semver("1:1.2.3"), Err(ParseVersionError::HasEpoch));
+        assert_eq!(Version::parse_semver("1.x.3"), Err(ParseVersionError::NonNumericComponent("x".to_string())));
+        assert_eq!(Version::parse_semver("1.0.0"), Ok(Version::parse("1.0.0")));
+        assert_eq!(Version::parse_semver("1.2.3-rc-1.x-y"), Ok(Version::parse("1.2.3-rc-1.x-y")));
+    }
+    #[test]
+    fn strict_semver_raw_input() {
+        let not_semver = |s: &str| Err(ParseVersionError::NotSemVer(s.to_string()));
+        assert_eq!(Version::parse_semver("1_2_3"), not_semver("1_2_3"));
+        assert_eq!(Version::parse_semver("1.2.3~rc"), not_semver("~rc"));
+        assert_eq!(Version::parse_semver("v1.2.3"), not_semver("v1.2.3"));
+        assert_eq!(Version::parse_semver(" 1.2.3 "), not_semver(" 1.2.3 "));
+        assert_eq!(Version::parse_semver("'1.2.3'"), not_semver("'1.2.3'"));
+        assert_eq!(Version::parse_semver("\"1.2.3-rc\""), not_semver("\"1.2.3"));
+        assert_eq!(Version::parse_semver("1.2.3\n"), not_semver("1.2.3\n"));
+    }
+    #[cfg(feature = "semver-compat")]
+    #[test]
+    fn semver_crate_rejects_tilde() {
+        let version = Version::parse("1.2.3~rc");
+        assert_eq!(semver::Version::try_from(&version), Err(ParseVersionError::NotSemVer("~rc".to_string())));
+    }
+    #[test]
+    fn strict_semver_identifiers() {
+        let not_semver = |s: &str| Err(ParseVersionError::NotSemVer(s.to_string()));
+        assert_eq!(Version::parse_semver("1.2.3-rc..1"), not_semver(""));
+        assert_eq!(Version::parse_semver("1.2.3-rc "), not_semver("rc "));
+        assert_eq!(Version::parse_semver("1.2.3-rc_1"), not_semver("rc_1"));
+        assert_eq!(Version::parse_semver("1.2.3-a+b+c"), not_semver("b+c"));
+        assert_eq!(Version::parse_semver("1.2.3+build.é"), not_semver("é"));
+        assert_eq!(Version::parse_semver("1.2.3-"), not_semver(""));
+        assert_eq!(Version::parse_semver("1.2.3+"), not_semver(""));
+        assert_eq!(Version::parse_semver("1.2.3-+b"), not_semver(""));
+        assert_eq!(Version::parse_semver("1.2.3-rc+b."), not_semver(""));
+    }
+    #[test]
+    fn display_round_trip() {
+        let inputs = ["1.2.3-alpha+001", "1:2.3.4", "2023.03.01", "2.sjf.5djf", "1.0.0-SNAPSHOT", "5", "1.0.0-rc.1+build.5", "6.1.7601.18741"];
+        for input in inputs {
+            assert_eq!(Version::parse(input).to_string(), input);
+        }
+        let empty = Version::new(Some(1), Vec::new(), Some("rc".to_string()), None);
+        assert_eq!(empty.to_string(), "1:-rc");
+        assert_eq!(Version::new(None, Vec::new(), None, None).to_string(), "");
+    }
+    #[test]
+    fn pre_release_case() {
+        let v1 = Version::parse("1.0.0-RC");
+        let v2 = Version::parse("1.0.0-rc");
+        assert_eq!(v1.cmp(&v2), Equal);
+        assert_eq!(v1, v2);
+        let mut set = HashSet::new();
+        set.insert(v1);
+        set.insert(v2);
+        assert_eq!(set.len(), 1);
+        assert_ne!(Version::parse("1.0.0-SNAPSHOT"), Version::parse("1.0.0-rc"));
+    }
+    #[test]
+    fn calver() {
+        assert!(Version::parse("2023.03.01").looks_like_calver());
+        assert!(Version::parse("2023.3").looks_like_calver());
+        assert!(!Version::parse("1.2.3").looks_like_calver());
+        assert!(!Version::parse("0999.1.1").looks_like_calver());
+        assert!(!Version::parse("20230301").looks_like_calver());
+        assert_eq!(Version::parse("2023.3.1").cmp(&Version::parse("2023.03.01")), Equal);
+        assert_eq!(Version::parse("2023.03").cmp(&Version::parse("2023.3.0")), Equal);
+        assert!(Version::parse("2023.03.01") < Version::parse("2023.10.01"));
+        assert!(Version::parse("2023.09") < Version::parse("2023.10"));
+        assert!(Version::parse("2024.01") > Version::parse("2023.12.31"));
+    }
+    #[test]
+    fn release_core() {
+        let v1 = Version::parse("1:2.3.4-rc+5");
+        assert_eq!(v1.to_release(), Version::parse("1:2.3.4"));
+        assert_eq!(v1.core_string(), "1:2.3.4");
+        assert_eq!(Version::parse("2.3.4+5").core_string(), "2.3.4");
+        assert_eq!(Version::parse("2.3.4-rc").core_string(), "2.3.4");
+        assert_eq!(Version::parse("2.3.4").to_release(), Version::parse("2.3.4"));
+    }
+    #[test]
+    fn tag_prefix() {
+        let v1 = Version::parse("v1.2.3");
+        assert_eq!(v1, Version::parse("1.2.3"));
+        assert_eq!(v1.components, ["1", "2", "3"]);
+        assert_eq!(v1.to_string(), "1.2.3");
+        assert_eq!(format!("{v1:#}"), "v1.2.3");
+        let v2: Version = "V2.0.0".parse().unwrap();
+        assert_eq!(v2, Version::parse("2.0.0"));
+        assert_eq!(format!("{v2:#}"), "V2.0.0");
+        let v3 = Version::parse("version1");
+        assert_eq!(v3.components, ["version1"]);
+        assert_eq!(format!("{v3:#}"), "version1");
+    }
+    #[test]
+    fn mixed_components() {
+        // numeric components are lower than non-numeric ones, like SemVer pre-release identifiers
+        assert_eq!(Version::parse("1.2").cmp(&Version::parse("1.beta")), Less);
+        assert_eq!(Version::parse("1.beta").cmp(&Version::parse("1.2")), Greater);
+        assert!(Version::parse("1.2.0") < Version::parse("1.2.alpha"));
+        assert!(Version::parse("1.2") < Version::parse("1.2.alpha"));
+        // non-numeric components are compared lexically
+        assert_eq!(Version::parse("1.x").cmp(&Version::parse("1.y")), Less);
+        assert_eq!(Version::parse("1.y").cmp(&Version::parse("1.x")), Greater);
+        assert_eq!(Version::parse("1.x").cmp(&Version::parse("1.x")), Equal);
+    }
+    #[test]
+    fn tilde_and_underscore() {
+        let v1 = Version::parse("1.0.0~beta");
+        assert_eq!(v1.components, ["1", "0", "0"]);
+        assert_eq!(v1.pre_release.as_deref(), Some("beta"));
+        assert_eq!(v1.to_string(), "1.0.0~beta");
+        assert!(v1 < Version::parse("1.0.0"));
+        assert!(v1 < Version::parse("1.0.0-alpha"));
+        assert!(Version::parse("1.0.0~alpha") < v1);
+        assert_ne!(v1, Version::parse("1.0.0-beta"));
+        assert_eq!(Version::parse("1_2_3"), Version::parse("1.2.3"));
+    }
+    #[test]
+    fn builder() {
+        let v1 = Version::builder().epoch(1).component("2").component("3").pre_release("rc").build();
+        assert_eq!(v1, Ok(Version::parse("1:2.3-rc")));
+        let v2 = Version::builder().component("1").build_metadata("001").build();
+        assert_eq!(v2, Ok(Version::parse("1+001")));
+        assert_eq!(Version::builder().pre_release("rc").build(), Err(ParseVersionError::NoComponents));
+        assert_eq!(Version::builder().component("").build(), Err(ParseVersionError::EmptyComponent));
+        let v3 = Version::builder().component("1").pre_release("").build_metadata("").build().unwrap();
+        assert_eq!((v3.pre_release.as_deref(), v3.build_metadata.as_deref()), (None, None));
+        assert_eq!(v3.to_string(), "1");
+        assert_eq!(v3, Version::parse("1"));
+        let v4 = Version::new(None, vec!["1".to_string()], Some(String::new()), Some(String::new()));
+        assert_eq!(v4.to_string(), "1");
+        for component in ["1.2", "1_2", "1-rc", "1~rc", "1+b", "1:2"] {
+            let error = ParseVersionError::SeparatorInComponent(component.to_string());
+            assert_eq!(Version::builder().component(component).build(), Err(error));
+        }
+    }
+    #[test]
+    fn compare_with_str() {
+        let v1 = Version::parse("1.2.3");
+        assert_eq!(v1.cmp_str("1.2.3"), Equal);
+        assert_eq!(v1.cmp_str("1.2.3-rc"), Greater);
+        assert_eq!(v1.cmp_str("2.0.0"), Less);
+        assert!(v1.eq_str("1.2.3"));
+        assert!(v1.eq_str("0:1.2.3"));
+        assert!(!v1.eq_str("1.2.4"));
+    }
+    #[test]
+    fn latest() {
+        let versions: Vec<Version> = ["1.2.0", "2.0.0-rc.1", "0.9.0", "1.10.0", "1.10.0-beta", "2.0.0-alpha"]
+            .into_iter()
+            .map(Version::parse)
+            .collect();
+        assert_eq!(Version::latest(&versions), Some(&versions[1]));
+        assert_eq!(Version::latest_stable(&versions), Some(&versions[3]));
+        let pre_releases = [Version::parse("1.0.0-rc")];
+        assert_eq!(Version::latest_stable(&pre_releases), None);
+        assert_eq!(Version::latest(&[]), None);
+    }
+    #[test]
+    fn overflowing_epoch() {
+        let epoch = "99999999999999999999999";
+        let result = format!("{epoch}:1.0.0").parse::<Version>();
+        assert_eq!(result, Err(ParseVersionError::BadEpoch(epoch.to_string())));
+        assert_eq!(format!("{}:1.0.0", u64::MAX).parse::<Version>().unwrap().epoch, Some(u64::MAX));
+        // no colon means no epoch, not a bad one
+        assert_eq!("1.0.0".parse::<Version>().unwrap().epoch, None);
+    }
+    #[test]
+    fn normalized() {
+        let v1 = Version::parse("1:1.2-rc+5");
+        assert_eq!(v1.normalized(2).to_string(), "1:1.2-rc+5");
+        assert_eq!(v1.normalized(3).to_string(), "1:1.2.0-rc+5");
+        assert_eq!(v1.normalized(4).to_string(), "1:1.2.0.0-rc+5");
+        let v2 = Version::parse("1.2.3.4");
+        assert_eq!(v2.normalized(2).components, ["1", "2", "3", "4"]);
+        assert_eq!(v2.normalized(4).components, ["1", "2", "3", "4"]);
+    }
+    #[test]
+    fn segmented_comparison() {
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_segmented(&Version::parse(b));
+        assert_eq!(cmp("1.5djf", "1.10djf"), Less);
+        assert_eq!(cmp("1.5a", "1.5b"), Less);
+        assert_eq!(cmp("1.5a", "1.5"), Greater);
+        assert_eq!(cmp("1.5a2", "1.5a10"), Less);
+        assert_eq!(cmp("1.05a", "1.5a"), Equal);
+        assert_eq!(cmp("1.a", "1.5"), Greater);
+        assert_eq!(cmp("1:1.0", "2.0"), Greater);
+        assert_eq!(cmp("1.0-rc", "1.0"), Less);
+    }
+    #[test]
+    fn caret_and_tilde() {
+        let caret = |version: &str, base: &str| Version::parse(version).satisfies_caret(&Version::parse(base));
+        assert!(caret("1.5.0", "1.2.3"));
+        assert!(!caret("2.0.0", "1.2.3"));
+        assert!(!caret("1.2.2", "1.2.3"));
+        assert!(caret("0.2.5", "0.2.3"));
+        assert!(!caret("0.3.0", "0.2.3"));
+        assert!(!caret("0.0.4", "0.0.3"));
+        assert!(!caret("1.0.0", "x.1"));
+        let tilde = |version: &str, base: &str| Version::parse(version).satisfies_tilde(&Version::parse(base));
+        assert!(tilde("1.2.9", "1.2.3"));
+        assert!(!tilde("1.3.0", "1.2.3"));
+        assert!(tilde("1.9.0", "1"));
+        assert!(!tilde("2.0.0", "1"));
+    }
+    #[test]
+    fn original() {
+        for input in ["1.2-3", "1_2-3", "v1.2.3", "1..3", "1.0.0~beta"] {
+            assert_eq!(Version::parse(input).original(), Some(input));
+        }
+        assert_eq!(Version::parse("1.2-3").to_string(), "1.2-3");
+        assert_eq!(Version::parse("1..3").to_string(), "1.3");
+        assert_eq!(Version::from_numbers(1, 2, 3).original(), None);
+        assert_eq!(Version::parse("1.2").bump_patch().unwrap().original(), None);
+        assert_eq!(Version::parse("1.2").normalized(3).original(), None);
+    }
+    #[test]
+    fn checked_comparison() {
+        let v1 = Version::parse("1.2.3");
+        assert_eq!(v1.try_cmp(&Version::parse("1.2.4")), Ok(Less));
+        assert_eq!(v1.try_cmp(&Version::parse("1.2.3-rc")), Ok(Greater));
+        assert_eq!(v1.try_cmp(&Version::parse("1.2")), Ok(Greater));
+        let error = Err(CompareError::NonNumericComponent("sjf".to_string()));
+        assert_eq!(v1.try_cmp(&Version::parse("2.sjf.5djf")), error);
+        assert_eq!(Version::parse("2.sjf.5djf").try_cmp(&v1), error);
+        let big = Version::parse("99999999999999999999999.0");
+        assert_eq!(big.try_cmp(&v1), Ok(Greater));
+        assert_eq!(v1.try_cmp(&big), Ok(Less));
+    }
+    #[test]
+    fn epoch_and_pre_release_matrix() {
+        let epochs = [("", 0), ("0:", 0), ("1:", 1)];
+        let pre_releases = [("-alpha", 0), ("-beta", 1), ("", 2)];
+        let mut cases = Vec::new();
+        for (epoch, epoch_rank) in epochs {
+            for (pre_release, pre_release_rank) in pre_releases {
+                cases.push((format!("{epoch}1.0.0{pre_release}"), (epoch_rank, pre_release_rank)));
+            }
+        }
+        for (a, a_rank) in &cases {
+            for (b, b_rank) in &cases {
+                let cmp = Version::parse(a).cmp(&Version::parse(b));
+                assert_eq!(cmp, a_rank.cmp(b_rank), "{a} vs {b}");
+            }
+        }
+        assert_eq!(Version::parse("1:1.0.0-alpha").cmp(&Version::parse("1:1.0.0")), Less);
+        assert_eq!(Version::parse("1:1.0.0-alpha").cmp(&Version::parse("1.0.0")), Greater);
+    }
+    #[test]
+    fn compare_with_str_operators() {
+        let v1 = Version::parse("1.2.3");
+        assert!(v1 == "1.2.3");
+        assert!(v1 != "1.2.4");
+        assert!(v1 < "2.0.0");
+        assert!(v1 >= "1.2");
+        assert!("1.2.3" == v1);
+        assert!("2.0.0" > v1);
+        assert!(*"1.0" < v1);
+        assert!(v1 == *"0:1.2.3");
+    }
+    #[test]
+    fn diff() {
+        let diff = |a: &str, b: &str| Version::parse(a).diff(&Version::parse(b));
+        assert_eq!(diff("1:1.2.3", "2:1.2.3"), VersionDiff::Epoch);
+        assert_eq!(diff("1.2.3", "2.2.3"), VersionDiff::Major);
+        assert_eq!(diff("1.2.3", "1.3.0"), VersionDiff::Minor);
+        assert_eq!(diff("1.2.3", "1.2.4"), VersionDiff::Patch);
+        assert_eq!(diff("6.1.7601.1", "6.1.7601"), VersionDiff::Extra);
+        assert_eq!(diff("1.0.0-alpha", "1.0.0-beta"), VersionDiff::PreRelease);
+        assert_eq!(diff("1.0.0-alpha", "1.0.0"), VersionDiff::PreRelease);
+        assert_eq!(diff("1.0.0+001", "1.0.0+002"), VersionDiff::Build);
+        assert_eq!(diff("1.2", "1.2.0"), VersionDiff::Equal);
+        assert_eq!(diff("1.0.0-RC", "1.0.0-rc"), VersionDiff::Equal);
+    }
+    #[test]
+    fn wildcard() {
+        let v1 = Version::parse("1.2.3-rc+5");
+        assert!(v1.matches_wildcard("1.2.*"));
+        assert!(v1.matches_wildcard("1.*"));
+        assert!(v1.matches_wildcard("*"));
+        assert!(v1.matches_wildcard("1.2.3"));
+        assert!(v1.matches_wildcard("*.2.3"));
+        assert!(!v1.matches_wildcard("1.2"));
+        assert!(!v1.matches_wildcard("2.*"));
+        assert!(!v1.matches_wildcard("1.2.4"));
+        assert!(Version::parse("1.2").matches_wildcard("1.2.*"));
+        assert!(Version::parse("1.2.0").matches_wildcard("1.2"));
+        assert!(Version::parse("1.02").matches_wildcard("1.2"));
+    }
+    #[test]
+    fn pre_release_leading_zeros() {
+        // lenient parsing reads "01" as the number 1
+        assert!(Version::parse("1.0.0-alpha.01") < Version::parse("1.0.0-alpha.2"));
+        assert_eq!(Version::parse("1.0.0-alpha.01").cmp(&Version::parse("1.0.0-alpha.1")), Equal);
+        // strict SemVer does not allow it
+        assert_eq!(Version::parse_semver("1.0.0-alpha.01"), Err(ParseVersionError::LeadingZero("01".to_string())));
+        assert!(Version::parse_semver("1.0.0-alpha.0").is_ok());
+        assert!(Version::parse_semver("1.0.0-alpha.0a").is_ok());
+    }
+    #[test]
+    fn compatibility() {
+        let compatible = |a: &str, b: &str| Version::parse(a).is_compatible_with(&Version::parse(b));
+        assert!(compatible("1.0.0", "1.9.0"));
+        assert!(compatible("1.9.0", "1.0.0"));
+        assert!(compatible("1.5.0", "1.2.0"));
+        assert!(!compatible("2.0.0", "1.2.0"));
+        assert!(!compatible("0.1.0", "0.2.0"));
+        assert!(compatible("0.2.1", "0.2.9"));
+        assert!(!compatible("0.0.1", "0.0.2"));
+        assert!(compatible("1", "1.4"));
+        assert!(!compatible("1:1.0.0", "1.0.0"));
+        assert!(!compatible("x.1", "x.1"));
+        assert!(compatible("1.x", "1.y"));
+    }
+    #[test]
+    fn four_components() {
+        let v1 = Version::parse("6.1.7601.18741");
+        assert_eq!(v1.to_string(), "6.1.7601.18741");
+        assert!(v1 > Version::parse("6.1.7601.17514"));
+        assert!(v1 < Version::parse("6.1.7602.0"));
+        assert!(v1 > Version::parse("6.1.7601"));
+        assert_eq!(Version::parse("6.1.7601").cmp(&Version::parse("6.1.7601.0")), Equal);
+        assert!(Version::parse("10.0.19041.1") > Version::parse("6.3.9600.16384"));
+        assert_eq!(Version::parse("6.1.7601.0").to_string(), "6.1.7601.0");
+    }
+    #[test]
+    fn canonical_string() {
+        for input in ["0:1.02.3-RC", "1.2.3-rc", "1.2.3-Rc", "0:001.2.03-rc", "v1.2.3-rc"] {
+            assert_eq!(Version::parse(input).to_canonical_string(), "1.2.3-rc", "{input}");
+        }
+        assert_eq!(Version::parse("2:2023.03.01-Beta.02+Build").to_canonical_string(), "2:2023.3.1-beta.2+Build");
+        assert_eq!(Version::parse("1.00.0~RC").to_canonical_string(), "1~rc");
+        assert_eq!(Version::parse("0.0.0").to_canonical_string(), "0");
+        let equal_pairs = [
+            ("1.2", "1.2.0"),
+            ("1.2", "1.2.0.00"),
+            ("1.dev01", "1.dev1"),
+            ("1.dev", "1.dev0"),
+            ("1.post002", "1.post2"),
+            ("1.0.0-RC.01", "1-rc.1"),
+            ("0:01.0.0+b", "1+b"),
+        ];
+        for (a, b) in equal_pairs {
+            let (a, b) = (Version::parse(a), Version::parse(b));
+            assert_eq!(a, b);
+            assert_eq!(a.to_canonical_string(), b.to_canonical_string(), "{a} and {b}");
+        }
+        assert_ne!(Version::parse("1.0.1").to_canonical_string(), Version::parse("1.1").to_canonical_string());
+        let versions: Vec<Version> = random_versions(300).iter().map(|s| Version::parse(s)).collect();
+        for a in &versions {
+            for b in &versions {
+                assert_eq!(a == b, a.to_canonical_string() == b.to_canonical_string(), "{a} and {b}");
+            }
+        }
+        assert_eq!(Version::parse("2.sjf.05djf").to_canonical_string(), "2.sjf.05djf");
+        let debian = |s| Version::parse_debian(s).unwrap().to_canonical_string();
+        assert_eq!(debian("1.2-3"), "1.2-3");
+        assert_ne!(debian("1.2-3"), debian("1.2-4"));
+        assert_eq!(debian("0:1.02~RC-3"), "1.2~rc-3");
+    }
+    #[test]
+    fn build_metadata_tie_breaker() {
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_with_build(&Version::parse(b));
+        assert_eq!(cmp("1.0.0+1", "1.0.0+2"), Less);
+        assert_eq!(cmp("1.0.0+exp", "1.0.0+1"), Greater);
+        assert_eq!(cmp("1.0.0+2", "1.0.0+10"), Less);
+        assert_eq!(cmp("1.0.0+build.2", "1.0.0+build.10"), Less);
+        assert_eq!(cmp("1.0.0", "1.0.0+1"), Less);
+        assert_eq!(cmp("1.0.0-rc+9", "1.0.0+1"), Less);
+        assert_eq!(cmp("1.0.0+1", "1.0.0+1"), Equal);
+    }
+    #[test]
+    fn parse_warnings() {
+        let (version, warnings) = Version::parse_verbose("v1..3");
+        assert_eq!(version.to_string(), "1.3");
+        assert_eq!(warnings, [ParseWarning::PrefixStripped('v'), ParseWarning::EmptyComponentsDropped(1)]);
+
+        let (version, warnings) = Version::parse_verbose("bad:1.2.3");
+        assert_eq!(version, Version::parse("1.2.3"));
+        assert_eq!(warnings, [ParseWarning::EpochDropped("bad".to_string())]);
+
+        let (version, warnings) = Version::parse_verbose("1:2.3.4");
+        assert_eq!(version, Version::parse("1:2.3.4"));
+        assert!(warnings.is_empty());
+
+        let (version, warnings) = Version::parse_verbose("");
+        assert_eq!(version, Version::default());
+        assert_eq!(warnings, [ParseWarning::Defaulted(ParseVersionError::Empty)]);
+    }
+    #[test]
+    fn bump_overflow() {
+        let version = Version::parse("18446744073709551615.18446744073709551615.18446744073709551615");
+        assert_eq!(version.bump_major(), None);
+        assert_eq!(version.bump_minor(), None);
+        assert_eq!(version.bump_patch(), None);
+        let version = Version::parse("1.18446744073709551615.3");
+        assert_eq!(version.bump_major().unwrap().to_string(), "2.0.0");
+        assert_eq!(version.bump_minor(), None);
+        assert_eq!(version.bump_patch().unwrap().to_string(), "1.18446744073709551615.4");
+    }
+    #[test]
+    fn zero_version() {
+        assert_eq!(Version::zero().to_string(), "0.0.0");
+        assert_eq!(Version::zero(), Version::parse("0.0.0"));
+        assert!(Version::zero() < Version::default());
+        assert_eq!(Version::default().to_string(), "0.0.1");
+        assert_eq!(Version::default().original(), None);
+    }
+    #[test]
+    fn trimming() {
+        let trimmed = |s: &str| Version::parse(s).trimmed().to_string();
+        assert_eq!(trimmed("1.0.0"), "1");
+        assert_eq!(trimmed("1.2.0"), "1.2");
+        assert_eq!(trimmed("0.0.0"), "0");
+        assert_eq!(trimmed("1.0.2"), "1.0.2");
+        assert_eq!(trimmed("1:2.0+5"), "1:2+5");
+        assert_eq!(Version::parse("1.2.0").trimmed().normalized(3), Version::parse("1.2.0"));
+    }
+    #[test]
+    fn components_above_u64_max() {
+        let v = Version::parse;
+        assert!(v("1.5") < v("1.99999999999999999999999"));
+        assert!(v("1.99999999999999999999999") < v("1.a"));
+        assert!(v("1.99999999999999999999") < v("1.100000000000000000000"));
+        assert!(v("18446744073709551615") < v("18446744073709551616"));
+        assert_eq!(v("1.0099999999999999999999").cmp(&v("1.99999999999999999999")), Equal);
+        assert!(v("1.0.0-99999999999999999999") < v("1.0.0-100000000000000000000"));
+        assert!(v("1.0.0-99999999999999999999") < v("1.0.0-alpha"));
+    }
+    #[test]
+    fn setting_components() {
+        let mut version = Version::parse("v1.2.3-rc");
+        assert_eq!(version.set_component(0, "2"), Ok(()));
+        assert_eq!(version.set_component(2, String::from("x")), Ok(()));
+        assert_eq!(version.to_string(), "2.2.x-rc");
+        assert_eq!(version.original(), None);
+
+        assert!(version.set_component(3, "4").is_err());
+        assert!(version.set_component(usize::MAX, "4").is_err());
+        assert!(version.set_component(0, "").is_err());
+        assert!(version.set_component(0, "1.1").is_err());
+        assert_eq!(version.to_string(), "2.2.x-rc");
+    }
+    #[test]
+    fn pep440_dev_and_post() {
+        let v = Version::parse;
+        assert!(v("1.2.3.dev1") < v("1.2.3"));
+        assert!(v("1.2.3") < v("1.2.3.post1"));
+        assert!(v("1.2.3.dev1") < v("1.2.3.post1"));
+        assert!(v("1.2.3.dev2") < v("1.2.3.dev10"));
+        assert!(v("1.2.3.post2") < v("1.2.3.post10"));
+        assert!(v("1.2.3.dev") < v("1.2.3.dev1"));
+        assert!(v("1.2.3.dev99") < v("1.2.3.0"));
+        assert!(v("1.2.2.post5") < v("1.2.3.dev1"));
+        assert!(v("1.2.3.post1") < v("1.2.4"));
+        // "postN" components are lower than other words, so the order stays transitive
+        assert!(v("1.post10") < v("1.post1a"));
+        assert!(v("1.post2") < v("1.post1a"));
+    }
+    #[test]
+    fn ignoring_epochs() {
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_ignore_epoch(&Version::parse(b));
+        assert_eq!(cmp("3:1.2.3", "1:1.2.4"), Less);
+        assert_eq!(cmp("1:1.2.4", "3:1.2.3"), Greater);
+        assert_eq!(cmp("2:1.2.3", "1.2.3"), Equal);
+        assert_eq!(cmp("2:1.2.3-rc", "1.2.3"), Less);
+        assert_eq!(cmp("1.2.3+1", "5:1.2.3+2"), Less);
+    }
+    #[test]
+    fn error_messages() {
+        let error = "bad:1.2.3".parse::<Version>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid epoch \"bad\"");
+        let error = "1..3".parse::<Version>().unwrap_err();
+        assert_eq!(error.to_string(), "version has an empty component");
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_errors() {
+        fn parse(version_str: &str) -> Result<Version, Box<dyn std::error::Error>> {
+            Ok(version_str.parse::<Version>()?)
+        }
+        assert!(parse("1.2.3").is_ok());
+        let error = parse("1..3").unwrap_err();
+        assert_eq!(error.to_string(), "version has an empty component");
+        assert!(error.source().is_none());
+    }
+    /// random but valid version strings, always the same ones (xorshift with a fixed seed)
+    fn random_versions(count: usize) -> Vec<String> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = |n: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % n as u64) as usize
+        };
+        let components = ["0", "1", "2", "10", "00", "007", "18446744073709551616", "x", "rc", "dev", "dev3", "post2", "post10", "post1a"];
+        let identifiers = ["alpha", "beta", "RC", "SNAPSHOT", "1", "2", "10", "01", "x-y"];
+        let builds = ["001", "1", "exp", "sha.5114f85"];
+        (0..count)
+            .map(|_| {
+                let mut version = String::new();
+                if next(4) == 0 {
+                    version += &format!("{}:", next(3));
+                }
+                let len = 1 + next(4);
+                let parts: Vec<&str> = (0..len).map(|_| components[next(components.len())]).collect();
+                version += &parts.join(".");
+                if next(2) == 0 {
+                    version += ["-", "~"][next(2)];
+                    let len = 1 + next(3);
+                    let parts: Vec<&str> = (0..len).map(|_| identifiers[next(identifiers.len())]).collect();
+                    version += &parts.join(".");
+                }
+                if next(3) == 0 {
+                    version += "+";
+                    version += builds[next(builds.len())];
+                }
+                version
+            })
+            .collect()
+    }
+    #[test]
+    fn properties_round_trip() {
+        for version_str in random_versions(2000) {
+            assert_eq!(Version::parse(&version_str).to_string(), version_str);
+            assert_eq!(version_str.parse::<Version>().map(|v| v.to_string()), Ok(version_str.clone()));
+        }
+    }
+    #[test]
+    fn properties_total_order() {
+        let versions: Vec<Version> = random_versions(80).iter().map(|s| Version::parse(s)).collect();
+        for a in &versions {
+            assert_eq!(a.cmp(a), Equal, "{a}");
+            for b in &versions {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{a} and {b}");
+                for c in &versions {
+                    if a.cmp(b) != Greater && b.cmp(c) != Greater {
+                        assert_ne!(a.cmp(c), Greater, "{a} <= {b} <= {c}");
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn pre_release_identifiers() {
+        assert_eq!(Version::parse("1.0.0-alpha.beta.3").pre_release_identifiers(), ["alpha", "beta", "3"]);
+        assert_eq!(Version::parse("1.0.0~rc").pre_release_identifiers(), ["rc"]);
+        assert!(Version::parse("1.0.0+rc.1").pre_release_identifiers().is_empty());
+    }
+    #[test]
+    fn clamping() {
+        let low = Version::parse("1.0.0");
+        let high = Version::parse("2.0.0");
+        let clamp = |s: &str| Version::parse(s).clamp_version(&low, &high).to_string();
+        assert_eq!(clamp("3.0.0"), "2.0.0");
+        assert_eq!(clamp("0.9.0"), "1.0.0");
+        assert_eq!(clamp("1.5.0-rc"), "1.5.0-rc");
+        assert_eq!(clamp("2.0.0-rc"), "2.0.0-rc");
+        assert_eq!(clamp("1.0.0"), "1.0.0");
+    }
+    #[test]
+    #[should_panic]
+    fn clamping_with_inverted_bounds() {
+        Version::parse("1.0.0").clamp_version(&Version::parse("2.0.0"), &Version::parse("1.0.0"));
+    }
+    #[test]
+    fn whitespace_and_quotes() {
+        assert_eq!(Version::parse(" 1.2.3 "), Version::parse("1.2.3"));
+        assert_eq!(Version::parse("\t1.0.0\n"), Version::parse("1.0.0"));
+        assert_eq!(Version::parse("'1.2.3'"), Version::parse("1.2.3"));
+        assert_eq!(Version::parse("\"v1.2.3-rc\"").to_string(), "1.2.3-rc");
+        assert_eq!(" '1.2.3' ".parse::<Version>().map(|v| v.to_string()), Ok("1.2.3".to_string()));
+        assert_eq!(Version::parse(" 1.2.3 ").original(), Some(" 1.2.3 "));
+        // only matching quotes are stripped
+        assert_eq!(Version::parse("'1.2.3\"").components, ["'1", "2", "3\""]);
+        assert_eq!("  ".parse::<Version>(), Err(ParseVersionError::Empty));
+        assert_eq!("''".parse::<Version>(), Err(ParseVersionError::Empty));
+    }
+    #[test]
+    fn release_targets() {
+        assert_eq!(Version::parse("1.2.0-rc.1").release_target().to_string(), "1.2.0");
+        assert_eq!(Version::parse("2:3.4.5-beta+9").release_target().to_string(), "2:3.4.5");
+        assert_eq!(Version::parse("1.0~rc").release_target().to_string(), "1.0");
+        assert!(Version::parse("1.2.0-rc.1") < Version::parse("1.2.0-rc.1").release_target());
+    }
+    #[test]
+    fn sorting_by_key() {
+        let mut versions: Vec<Version> = random_versions(10_000).iter().map(|s| Version::parse(s)).collect();
+        versions.sort_by_cached_key(Version::sort_key);
+        for pair in versions.windows(2) {
+            assert_ne!(pair[0].cmp(&pair[1]), Greater, "{} and {}", pair[0], pair[1]);
+        }
+        for pair in versions.chunks(2).filter(|pair| pair.len() == 2) {
+            assert_eq!(pair[0].sort_key().cmp(&pair[1].sort_key()), pair[0].cmp(&pair[1]));
+        }
+        let key = |s: &str| Version::parse(s).sort_key();
+        assert_eq!(key("1.2"), key("1.2.0.00"));
+        assert!(key("1.2.0.dev1") < key("1.2"));
+        assert!(key("18446744073709551616") < key(&"9".repeat(40)));
+        assert!(key(&"9".repeat(38)) < key(&"1".repeat(39)));
+        assert!(key("1.0.0~rc") < key("1.0.0-alpha"));
+        assert!(key("1.0.0-alpha") < key("1.0.0"));
+    }
+    #[test]
+    fn coercion() {
+        let coerce = |s: &str| Version::coerce(s).to_string();
+        assert_eq!(coerce("v1.2"), "1.2.0");
+        assert_eq!(coerce("2023.03"), "2023.3.0");
+        assert_eq!(coerce("1"), "1.0.0");
+        assert_eq!(coerce("1.2.3.4"), "1.2.3");
+        assert_eq!(coerce("1.2.3-beta+5"), "1.2.3");
+        assert_eq!(coerce("release-4.5rc1"), "4.5.0");
+        assert_eq!(coerce("1.x.3"), "1.0.0");
+        assert_eq!(coerce("1..3"), "1.0.0");
+        assert_eq!(coerce("foo"), "0.0.0");
+        assert_eq!(coerce(""), "0.0.0");
+    }
+    #[test]
+    fn eq_agrees_with_cmp() {
+        let pairs = [
+            ("1.2", "1.2.0"),
+            ("1.2.3", "1.2.3"),
+            ("0:1.2.3", "1.2.3"),
+            ("v1.2.3", "1.2.3"),
+            ("1_2_3", "1.2.3"),
+            ("1.02.3", "1.2.3"),
+            ("1.0.0-RC.1", "1.0.0-rc.1"),
+            ("1.0.0-rc.01", "1.0.0-rc.1"),
+            ("1.0.0~rc", "1.0.0-rc"),
+            ("1.0.0+1", "1.0.0+2"),
+            ("1.0.0+1", "1.0.0"),
+            ("1.2.3.dev", "1.2.3.dev0"),
+            ("1.2.3", "1.2.4"),
+            ("2.sjf.5djf", "2.sjf.5djf"),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (Version::parse(a), Version::parse(b));
+            assert_eq!(a == b, a.cmp(&b) == Equal, "{a} and {b}");
+            assert_eq!(a == b, a.sort_key() == b.sort_key(), "{a} and {b}");
+        }
+        let set: HashSet<Version> = ["1.2", "1.2.0", "v1.2.0.0", "1.2.1"].into_iter().map(Version::parse).collect();
+        assert_eq!(set.len(), 2);
+    }
+    #[test]
+    fn epoch_accessors() {
+        let v1 = Version::parse("1:2.3.4");
+        assert_eq!(v1.epoch_or_zero(), 1);
+        assert!(v1.has_epoch());
+        let v2 = Version::parse("2.3.4");
+        assert_eq!(v2.epoch_or_zero(), 0);
+        assert!(!v2.has_epoch());
+        let v3 = Version::parse("0:2.3.4");
+        assert_eq!(v3.epoch_or_zero(), 0);
+        assert!(v3.has_epoch());
+    }
+    #[test]
+    fn ranges() {
+        let range = Version::range("1.2.0".."2.0.0");
+        let contains = |s: &str| range.contains(&Version::parse(s));
+        assert!(contains("1.2.0"));
+        assert!(contains("1.2.0+5"));
+        assert!(contains("1.9.9"));
+        assert!(contains("2.0.0-rc"));
+        assert!(!contains("1.2.0-rc"));
+        assert!(!contains("1.1.9"));
+        assert!(!contains("2.0.0"));
+        assert!(!contains("2.0.0+5"));
+
+        assert_eq!(VersionRange::parse("1.2.0..2.0.0"), Ok(range.clone()));
+        assert_eq!(VersionRange::from(Version::parse("1.2")..Version::parse("2")), range);
+        assert_eq!(VersionRange::parse("1.2.0"), Err(ParseVersionError::BadRequirement("1.2.0".to_string())));
+        assert_eq!(VersionRange::parse("1.2.0.."), Err(ParseVersionError::Empty));
+        // an empty range contains nothing
+        assert!(!Version::range("2.0.0".."1.0.0").contains(&Version::parse("1.5.0")));
+    }
+    #[test]
+    fn parsing_lists() {
+        let input = "1.0.0\n\n  1.1.0 , 1.2.0\r\nnot..valid\n2.0.0,\n";
+        let versions = Version::parse_many(input);
+        let versions: Vec<String> = versions.iter().map(Version::to_string).collect();
+        assert_eq!(versions, ["1.0.0", "1.1.0", "1.2.0", "not.valid", "2.0.0"]);
+
+        let errors = Version::try_parse_many(input).unwrap_err();
+        assert_eq!(errors, [(4, ParseVersionError::EmptyComponent)]);
+        let versions = Version::try_parse_many("1.0.0\n\n2.0.0").unwrap();
+        assert_eq!(versions, [Version::parse("1.0.0"), Version::parse("2.0.0")]);
+        assert_eq!(Version::try_parse_many(""), Ok(Vec::new()));
+    }
+    #[test]
+    fn pre_release_after_padded_core() {
+        let v = Version::parse;
+        assert_eq!(v("1.2").cmp(&v("1.2.0")), Equal);
+        assert!(v("1.2-alpha") < v("1.2.0"));
+        assert!(v("1.2.0-rc") < v("1.2"));
+        assert!(v("1.2.0.0-rc") < v("1.2"));
+        assert!(v("1.2-alpha") < v("1.2.0-beta"));
+        assert_eq!(v("1.2-rc"), v("1.2.0-rc"));
+        assert!(v("1.2-rc") > v("1.1.9"));
+        assert!(v("1.2~rc") < v("1.2.0-rc"));
+        assert!(v("1.2.0-rc").cmp_precedence(&v("1.2+5")).is_lt());
+    }
+    #[test]
+    fn version_schemes() {
+        let scheme = |s: &str| Version::parse(s).scheme();
+        assert_eq!(scheme("1.2.3"), VersionScheme::SemVer);
+        assert_eq!(scheme("1.2.3-rc+5"), VersionScheme::SemVer);
+        assert_eq!(scheme("1.2"), VersionScheme::SemVer);
+        assert_eq!(scheme("2023.03.01"), VersionScheme::CalVer);
+        assert_eq!(scheme("2.sjf.5djf"), VersionScheme::Unknown);
+
+        let v1 = Version::parse("1.2.3");
+        assert_eq!(v1.try_cmp_same_scheme(&Version::parse("1.10.0")), Ok(Less));
+        let error = v1.try_cmp_same_scheme(&Version::parse("2.sjf.5djf")).unwrap_err();
+        assert_eq!(error, CompareError::DifferentSchemes(VersionScheme::SemVer, VersionScheme::Unknown));
+        assert_eq!(error.to_string(), "can not compare versions of different schemes (SemVer and unknown)");
+    }
+    #[test]
+    fn chainable_setters() {
+        let version = Version::from_numbers(1, 2, 3).with_pre_release("rc.1").with_build_metadata("build.42");
+        assert_eq!(version.to_string(), "1.2.3-rc.1+build.42");
+        assert_eq!(version.original(), None);
+        assert_eq!(version.clone().with_pre_release("").to_string(), "1.2.3+build.42");
+        assert_eq!(version.clone().with_build_metadata(None).to_string(), "1.2.3-rc.1");
+        assert_eq!(Version::parse("1.0~rc").with_pre_release("rc2").to_string(), "1.0~rc2");
+        assert_eq!(Version::parse("1.0~rc").with_pre_release(None).with_pre_release("rc").to_string(), "1.0-rc");
+    }
+    #[test]
+    fn display_components() {
+        assert_eq!(Version::parse("5").to_string(), "5");
+        assert_eq!(Version::parse("1.2").to_string(), "1.2");
+        assert_eq!(Version::parse("1.2.3.4.5").to_string(), "1.2.3.4.5");
+        assert_eq!(Version::new(None, Vec::new(), None, None).to_string(), "");
+        assert_eq!(Version::new(Some(1), Vec::new(), Some("rc".to_string()), None).to_string(), "1:-rc");
+        assert_eq!(Version::new(None, vec![String::new()], None, None).to_string(), "");
+    }
+    #[test]
+    fn ranked_pre_releases() {
+        let ranks = [("snapshot", 1), ("alpha", 2), ("beta", 3), ("m", 4), ("rc", 5)];
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_ranked(&Version::parse(b), &ranks);
+        assert_eq!(cmp("1.0.0-snapshot", "1.0.0-alpha"), Less);
+        assert_eq!(cmp("1.0.0-beta.2", "1.0.0-m1"), Less);
+        assert_eq!(cmp("1.0.0-m2", "1.0.0-M10"), Less);
+        assert_eq!(cmp("1.0.0-m10", "1.0.0-rc1"), Less);
+        assert_eq!(cmp("1.0.0-rc.1", "1.0.0-rc.2"), Less);
+        assert_eq!(cmp("1.0.0-rc", "1.0.0"), Less);
+        // unknown tags are after ranked ones, and compared lexically
+        assert_eq!(cmp("1.0.0-rc", "1.0.0-nightly"), Less);
+        assert_eq!(cmp("1.0.0-dev", "1.0.0-nightly"), Less);
+        assert_eq!(cmp("1.0.0-1", "1.0.0-snapshot"), Less);
+        // everything else is compared like `cmp`
+        assert_eq!(cmp("1.0.0-snapshot", "0.9.0"), Greater);
+        assert_eq!(cmp("1.0.0-rc+1", "1.0.0-rc+2"), Less);
+
+        let default = |a: &str, b: &str| Version::parse(a).cmp_ranked(&Version::parse(b), Version::PRE_RELEASE_RANKS);
+        assert_eq!(default("1.0.0-alpha", "1.0.0-beta"), Less);
+        assert_eq!(default("1.0.0-milestone.2", "1.0.0-rc"), Less);
+        assert_eq!(default("1.0.0-rc.3", "1.0.0-snapshot"), Less);
+
+        // without "snapshot" in the table, SNAPSHOT stays the lowest
+        let ranks = [("rc", 1)];
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_ranked(&Version::parse(b), &ranks);
+        assert_eq!(cmp("1.0.0-SNAPSHOT", "1.0.0-1"), Less);
+        assert_eq!(cmp("1.0.0-1", "1.0.0-rc"), Less);
+        assert_eq!(cmp("1.0.0-SNAPSHOT", "1.0.0-rc"), Less);
+        // the order is total for any table
+        let identifiers = ["SNAPSHOT", "snapshot", "1", "2", "10", "rc", "RC1", "rc.2", "alpha", "m1", "x", "SNAPSHOT.1"];
+        for ranks in [&[("rc", 1)][..], &[("alpha", 1), ("m", 2)], Version::PRE_RELEASE_RANKS, &[]] {
+            let versions: Vec<Version> = identifiers.iter().map(|id| Version::parse(&format!("1.0.0-{id}"))).collect();
+            for a in &versions {
+                for b in &versions {
+                    assert_eq!(a.cmp_ranked(b, ranks), b.cmp_ranked(a, ranks).reverse(), "{a} and {b}");
+                    for c in &versions {
+                        if a.cmp_ranked(b, ranks).is_le() && b.cmp_ranked(c, ranks).is_le() {
+                            assert!(a.cmp_ranked(c, ranks).is_le(), "{a}, {b} and {c} with {ranks:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn zero_and_initial_development() {
+        let v = Version::parse;
+        assert!(v("0.0.0").is_zero());
+        assert!(v("0.0.0").is_initial_development());
+        assert!(!v("0.5.0").is_zero());
+        assert!(v("0.5.0").is_initial_development());
+        assert!(!v("1.0.0").is_zero());
+        assert!(!v("1.0.0").is_initial_development());
+        assert!(v("0.00-rc").is_zero());
+        assert!(!v("0.x").is_zero());
+        assert!(!v("x.1").is_initial_development());
+    }
+    #[test]
+    fn next_pre_release() {
+        let bump = |s: &str| Version::parse(s).bump_prerelease().map(|v| v.to_string());
+        assert_eq!(bump("1.2.0-rc.1"), Some("1.2.0-rc.2".to_string()));
+        assert_eq!(bump("1.2.0-rc.9+5"), Some("1.2.0-rc.10".to_string()));
+        assert_eq!(bump("1.2.0-beta"), Some("1.2.0-beta.1".to_string()));
+        assert_eq!(bump("1.2.0-1"), Some("1.2.0-2".to_string()));
+        assert_eq!(bump("1:1.2~rc.1"), Some("1:1.2~rc.2".to_string()));
+        assert_eq!(bump("1.2.0"), None);
+        assert_eq!(bump("1.2.0-rc.18446744073709551615"), None);
+        assert_eq!(bump("1.0-rc."), Some("1.0-rc.1".to_string()));
+        assert!(Version::parse("1.2.0-rc.1") < Version::parse("1.2.0-rc.1").bump_prerelease().unwrap());
+    }
+    #[test]
+    fn raw_comparison() {
+        let versions = random_versions(150);
+        let extra = ["", "bad:1.2.3", "1:", "..", "v1..3", " '1.2' ", "-rc", "0.0.1", "1.2.3-RC+5"];
+        let all: Vec<&str> = versions.iter().map(String::as_str).chain(extra).collect();
+        for a in &all {
+            for b in &all {
+                assert_eq!(cmp_raw(a, b), Version::parse(a).cmp(&Version::parse(b)), "{a:?} and {b:?}");
+            }
+        }
+    }
+    #[test]
+    fn version_req_pre_releases() {
+        let matches = |req: &str, version: &str| VersionReq::parse(req).unwrap().matches(&Version::parse(version));
+        assert!(!matches("^1.2.3", "2.0.0-rc"));
+        assert!(!matches("^1.2.3", "1.5.0-rc"));
+        assert!(!matches(">=1.2.0", "1.3.0-alpha"));
+        assert!(matches(">=1.2.0-rc", "1.2.0-rc.1"));
+        assert!(matches(">=1.2.0-rc", "1.2.0"));
+        assert!(!matches(">=1.2.0-rc", "1.3.0-alpha"));
+        assert!(!matches(">=1.2.0-rc", "1.2.0-beta"));
+        assert!(matches("^1.2.0-beta", "1.2.0-beta.2"));
+        assert!(matches(">=1.0.0, <1.2.0-rc.2", "1.2.0-rc.1"));
+        assert!(matches("=1.2.0-rc", "1.2.0-rc"));
+    }
+    #[test]
+    fn parsing_with_fallback() {
+        let fallback = Version::parse("9.9.9");
+        assert_eq!(Version::parse_or("1..3", fallback.clone()), fallback);
+        assert_eq!(Version::parse_or("bad:1.2.3", fallback.clone()), fallback);
+        assert_eq!(Version::parse_or("", fallback.clone()), fallback);
+        assert_eq!(Version::parse_or("v1.2.3", fallback).to_string(), "1.2.3");
+        assert_eq!(Version::parse_or_default("1..3"), Version::default());
+        assert_eq!(Version::parse_or_default("2.0"), Version::parse("2.0"));
+    }
+    #[test]
+    fn strict_length() {
+        let v = Version::parse;
+        assert_eq!(v("1.2.0.0.0").cmp(&v("1.2")), Equal);
+        assert_eq!(v("1.2.0.0").cmp_strict_length(&v("1.2")), Greater);
+        assert_eq!(v("1.2").cmp_strict_length(&v("1.2.0")), Less);
+        assert_eq!(v("1.2.0").cmp_strict_length(&v("1.2.0")), Equal);
+        assert_eq!(v("1.3").cmp_strict_length(&v("1.2.0.0")), Greater);
+        assert_eq!(v("1.2-rc").cmp_strict_length(&v("1.2.0-rc")), Less);
+    }
+    #[test]
+    fn git_tags() {
+        let version = Version::from_tag("v1.2.3");
+        assert_eq!(version.to_tag(), "v1.2.3");
+        assert_eq!(version.to_string(), "1.2.3");
+        let version = Version::from_tag("V2.0.0");
+        assert_eq!(version.to_tag(), "V2.0.0");
+        assert_eq!(version.to_string(), "2.0.0");
+        let version = Version::from_tag("1.2.3");
+        assert_eq!(version.to_tag(), "1.2.3");
+        assert_eq!(Version::from_tag("v1.2.3-rc+5").to_tag(), "v1.2.3-rc+5");
+        assert_eq!(Version::from_tag("v1.2.3"), Version::from_tag("1.2.3"));
+    }
+    #[test]
+    fn tokenizer() {
+        use crate::parser::{tokenize, Tokens};
+        let tokens = |prefix, epoch, core, pre_release, tilde, build_metadata| Tokens {
+            prefix,
+            epoch,
+            core,
+            pre_release,
+            tilde,
+            build_metadata,
+        };
+        assert_eq!(tokenize("1.2.3"), Some(tokens(None, None, "1.2.3", None, false, None)));
+        assert_eq!(tokenize("1:2.3"), Some(tokens(None, Some("1"), "2.3", None, false, None)));
+        assert_eq!(tokenize("1.0-rc.1"), Some(tokens(None, None, "1.0", Some("rc.1"), false, None)));
+        assert_eq!(tokenize("1.0~rc"), Some(tokens(None, None, "1.0", Some("rc"), true, None)));
+        assert_eq!(tokenize("1.0+b-1"), Some(tokens(None, None, "1.0", None, false, Some("b-1"))));
+        assert_eq!(
+            tokenize(" 'v2:1.0-rc+5' "),
+            Some(tokens(Some('v'), Some("2"), "1.0", Some("rc"), false, Some("5")))
+        );
+        assert_eq!(tokenize("bad:-+"), Some(tokens(None, Some("bad"), "", None, false, None)));
+        assert_eq!(tokenize(" "), None);
+        assert_eq!(tokenize("1_2.3").unwrap().components().collect::<Vec<_>>(), ["1", "2", "3"]);
+        for input in ["1:2.3.4-rc.1+5", "1.0~beta", "2.sjf.5djf", "1.2-3"] {
+            assert_eq!(Version::parse(input).to_string(), input);
+        }
+    }
+    #[test]
+    fn pre_release_tags() {
+        let version = Version::parse("1.0.0-rc.2");
+        assert_eq!(version.prerelease_tag(), Some("rc"));
+        assert!(version.is_prerelease_tag("RC"));
+        assert!(version.is_prerelease_tag("rc"));
+        assert!(!version.is_prerelease_tag("beta"));
+        assert!(!version.is_prerelease_tag("rc.2"));
+        assert_eq!(Version::parse("1.0.0~beta").prerelease_tag(), Some("beta"));
+        assert_eq!(Version::parse("1.0.0").prerelease_tag(), None);
+        assert!(!Version::parse("1.0.0").is_prerelease_tag(""));
+    }
+    #[test]
+    fn custom_component_comparison() {
+        let reversed = |a: &str, b: &str| b.parse::<u64>().unwrap_or(0).cmp(&a.parse::<u64>().unwrap_or(0));
+        let cmp = |a: &str, b: &str| Version::parse(a).cmp_by(&Version::parse(b), reversed);
+        assert_eq!(cmp("1.2.3", "1.10.0"), Greater);
+        assert_eq!(cmp("2.0", "1.0"), Less);
+        assert_eq!(cmp("1.2", "1.2.0"), Equal);
+        // epochs and pre-releases are compared as usual
+        assert_eq!(cmp("1:1.0", "2.0"), Greater);
+        assert_eq!(cmp("1.0-rc", "1.0"), Less);
+    }
+    #[test]
+    fn build_metadata_identifiers() {
+        assert_eq!(Version::parse("1.0.0+git.abc123").build_metadata_identifiers(), ["git", "abc123"]);
+        assert_eq!(Version::parse("1.0.0+20230301144500").build_metadata_identifiers(), ["20230301144500"]);
+        assert!(Version::parse("1.0.0-rc.1").build_metadata_identifiers().is_empty());
+    }
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn build_timestamps() {
+        use chrono::NaiveDate;
+        let timestamp = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap().and_hms_opt(14, 45, 0).unwrap();
+        assert_eq!(Version::parse("1.0.0+20230301144500").build_timestamp(), Some(timestamp));
+        assert_eq!(Version::parse("1.0.0+git.20230301144500").build_timestamp(), Some(timestamp));
+        assert_eq!(Version::parse("1.0.0+git.abc123").build_timestamp(), None);
+        assert_eq!(Version::parse("1.0.0+20231301144500").build_timestamp(), None);
+        assert_eq!(Version::parse("1.0.0+2023030114450").build_timestamp(), None);
+        assert_eq!(Version::parse("1.0.0").build_timestamp(), None);
+    }
+    #[test]
+    fn scheme_aware_partial_cmp() {
+        let cmp = |a: &str, b: &str| Version::parse(a).partial_cmp_scheme_aware(&Version::parse(b));
+        assert_eq!(cmp("2023.03.01", "1.2.3"), None);
+        assert_eq!(cmp("1.2.3", "2023.03.01"), None);
+        assert_eq!(cmp("1.2.3", "2.sjf.5djf"), None);
+        assert_eq!(cmp("1.2.3", "1.10.0"), Some(Less));
+        assert_eq!(cmp("2023.03.01", "2023.3.1"), Some(Equal));
+        assert_eq!(Version::parse("2023.03.01").partial_cmp(&Version::parse("1.2.3")), Some(Greater));
+    }
+    #[cfg(feature = "semver-compat")]
+    #[test]
+    fn semver_crate_conversions() {
+        let semver_version = semver::Version::parse("1.2.3-rc.1+build").unwrap();
+        let version = Version::from(semver_version.clone());
+        assert_eq!(version.to_string(), "1.2.3-rc.1+build");
+        assert_eq!(semver::Version::try_from(&version), Ok(semver_version));
+        let version = Version::from(semver::Version::new(1, 2, 3));
+        assert_eq!(version, Version::parse("1.2.3"));
+        assert_eq!(version.pre_release, None);
+        assert_eq!(semver::Version::try_from(&version), Ok(semver::Version::new(1, 2, 3)));
+
+        let try_from = |s: &str| semver::Version::try_from(&Version::parse(s));
+        assert_eq!(try_from("1:1.2.3"), Err(ParseVersionError::HasEpoch));
+        assert_eq!(try_from("1.2"), Err(ParseVersionError::WrongComponentCount(2)));
+        assert_eq!(try_from("1.x.3"), Err(ParseVersionError::NonNumericComponent("x".to_string())));
+        assert_eq!(try_from("1.2.3-rc_1"), Err(ParseVersionError::NotSemVer("rc_1".to_string())));
+        assert_eq!(
+            try_from("18446744073709551616.0.0"),
+            Err(ParseVersionError::NotSemVer("18446744073709551616".to_string()))
+        );
+    }
+    #[test]
+    fn numeric_normalization() {
+        let normalize = |s: &str| Version::parse(s).normalize_numeric().to_string();
+        assert_eq!(normalize("1.02.3"), "1.2.3");
+        assert_eq!(normalize("2023.03.01"), "2023.3.1");
+        assert_eq!(normalize("0.00.000"), "0.0.0");
+        assert_eq!(normalize("01.x01.1-rc.01"), "1.x01.1-rc.01");
+        // without normalizing, the padding is kept
+        assert_eq!(Version::parse("2023.03.01").to_string(), "2023.03.01");
+        assert_eq!(Version::parse("2023.03.01").normalize_numeric().original(), None);
+        assert_eq!(Version::parse("1.2.3").normalize_numeric().original(), Some("1.2.3"));
+    }
+    #[test]
+    fn numeric_fast_path() {
+        use crate::version::{cmp_component, Component};
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        }
+        // random digits, between `min` and `max` of them
+        fn digits(state: &mut u64, min: u64, max: u64) -> String {
+            let len = min + next(state) % (max - min + 1);
+            (0..len).map(|_| char::from(b'0' + (next(state) % 10) as u8)).collect()
+        }
+        let state = &mut 0x9e37_79b9_7f4a_7c15_u64;
+        let max = u128::MAX.to_string();
+        for _ in 0..20_000 {
+            // numbers around the `u128` limit (39 digits) hand off from `Numeric` to `BigNumeric`
+            let mut a = match next(state) % 6 {
+                0 => digits(state, 1, 4),
+                1 => next(state).to_string(),
+                2 => digits(state, 37, 41),
+                3 => digits(state, 40, 69),
+                4 => format!("{}{}", &max[..38], digits(state, 1, 2)),
+                _ => max.clone(),
+            };
+            // leading zeros do not change the number
+            if next(state).is_multiple_of(3) {
+                a.insert_str(0, &"0".repeat((1 + next(state) % 3) as usize));
+            }
+            let b = match next(state) % 5 {
+                0 => a.clone(),
+                1 => format!("0{a}"),
+                2 => digits(state, a.len() as u64, a.len() as u64),
+                _ => digits(state, 1, 60),
+            };
+            let expected = Component::new(&a).cmp(&Component::new(&b));
+            assert_eq!(cmp_component(&a, &b), expected, "{a} and {b}");
+            let (a, b) = (Version::parse(&format!("1.{a}")), Version::parse(&format!("1.{b}")));
+            assert_eq!(a.cmp(&b), expected, "{a} and {b}");
+            assert_eq!(a.sort_key().cmp(&b.sort_key()), expected, "{a} and {b}");
+        }
+    }
+    #[test]
+    fn multiple_requirements() {
+        let reqs = [VersionReq::parse(">=1.0.0").unwrap(), VersionReq::parse("<2.0.0").unwrap()];
+        assert!(Version::parse("1.5.0").matches_all(&reqs));
+        assert!(Version::parse("1.5.0").matches_any(&reqs));
+        assert!(!Version::parse("2.1.0").matches_all(&reqs));
+        assert!(Version::parse("2.1.0").matches_any(&reqs));
+        let reqs = [VersionReq::parse("<1.0.0").unwrap(), VersionReq::parse(">=3.0.0").unwrap()];
+        assert!(!Version::parse("2.1.0").matches_any(&reqs));
+        assert!(Version::parse("2.1.0").matches_all(&[]));
+        assert!(!Version::parse("2.1.0").matches_any(&[]));
+    }
+    #[test]
+    fn empty_pre_release_and_build_metadata() {
+        let version = Version::parse("1.0.0-");
+        assert_eq!(version.pre_release, None);
+        assert_eq!(version.to_string(), "1.0.0");
+        assert_eq!(version, Version::parse("1.0.0"));
+        let version = Version::parse("1.0.0+");
+        assert_eq!(version.build_metadata, None);
+        assert_eq!(version.to_string(), "1.0.0");
+        assert_eq!(Version::parse("1.0.0~+").to_string(), "1.0.0");
+        assert_eq!("1.0.0-+".parse::<Version>().map(|v| v.to_string()), Ok("1.0.0".to_string()));
+        assert_eq!(Version::parse("1.0.0--").pre_release.as_deref(), Some("-"));
+        assert_eq!(cmp_raw("1.0.0-", "1.0.0"), Equal);
+    }
+    #[test]
+    fn dialect_strings() {
+        let version = Version::parse("1:2.3.4-rc+5");
+        assert_eq!(version.to_semver_string(), Err(ParseVersionError::HasEpoch));
+        assert_eq!(version.to_debian_string(), "1:2.3.4-rc+5");
+        let version = Version::parse("v2.3.4-rc+5");
+        assert_eq!(version.to_semver_string(), Ok("2.3.4-rc+5".to_string()));
+        assert_eq!(version.to_debian_string(), "0:2.3.4-rc+5");
+        assert_eq!(Version::parse("0:1.0").to_semver_string(), Err(ParseVersionError::HasEpoch));
+        let semver_string = |s: &str| Version::parse(s).to_semver_string();
+        assert_eq!(semver_string("1.0.0~beta"), Err(ParseVersionError::NotSemVer("~beta".to_string())));
+        assert_eq!(semver_string("1.2"), Err(ParseVersionError::WrongComponentCount(2)));
+        assert_eq!(semver_string("1.x.3"), Err(ParseVersionError::NonNumericComponent("x".to_string())));
+        assert_eq!(semver_string("01.2.3"), Err(ParseVersionError::LeadingZero("01".to_string())));
+        assert_eq!(semver_string("1.2.3-rc_1"), Err(ParseVersionError::NotSemVer("rc_1".to_string())));
+    }
+    #[test]
+    fn satisfying_versions() {
+        let versions: Vec<Version> = ["1.1.9", "1.2.0", "2.0.0", "1.10.1", "1.3.0-rc", "0.9.0", "1.10.0"]
+            .into_iter()
+            .map(Version::parse)
+            .collect();
+        let req = VersionReq::parse("^1.2.0").unwrap();
+        assert_eq!(Version::max_satisfying(&versions, &req).unwrap().to_string(), "1.10.1");
+        assert_eq!(Version::min_satisfying(&versions, &req).unwrap().to_string(), "1.2.0");
+        let req = VersionReq::parse("^3.0").unwrap();
+        assert_eq!(Version::max_satisfying(&versions, &req), None);
+        assert_eq!(Version::min_satisfying(&[], &req), None);
+    }
+    #[test]
+    fn snapshot_is_oldest() {
+        let v = Version::parse;
+        assert!(v("1.0.0-SNAPSHOT") < v("1.0.0-alpha"));
+        assert!(v("1.0.0-alpha") < v("1.0.0-rc"));
+        assert!(v("1.0.0-rc") < v("1.0.0"));
+        assert!(v("1.0.0-SNAPSHOT") < v("1.0.0-1"));
+        assert!(v("1.0.0-snapshot") < v("1.0.0-rc"));
+        assert_eq!(v("1.0.0-snapshot"), v("1.0.0-SNAPSHOT"));
+        assert!(v("1.0.0-rc.SNAPSHOT") < v("1.0.0-rc.1"));
+        assert!(v("1.0.0-SNAPSHOT") > v("0.9.0"));
+        assert!(v("1.0.0-SNAPSHOT").sort_key() < v("1.0.0-1").sort_key());
+    }
+    #[test]
+    fn component_access() {
+        let version = Version::parse("1.x.3-rc");
+        assert_eq!(version.len(), 3);
+        assert!(!version.is_empty());
+        assert_eq!(version.get_component(0), Some("1"));
+        assert_eq!(version.get_component(1), Some("x"));
+        assert_eq!(version.get_component(2), Some("3"));
+        assert_eq!(version.get_component(3), None);
+        assert!(Version::new(None, Vec::new(), None, None).is_empty());
+    }
+    #[test]
+    fn family_key() {
+        let version = Version::parse("1.2.3");
+        assert_eq!(version.family_key(1), "1");
+        assert_eq!(version.family_key(2), "1.2");
+        assert_eq!(version.family_key(4), "1.2.3");
+        assert_eq!(version.family_key(0), "");
+    }
+    #[test]
+    fn display_precision_and_width() {
+        let version = Version::parse("1:1.2.3-rc+5");
+        assert_eq!(format!("{version:.1}"), "1:1");
+        assert_eq!(format!("{version:.2}"), "1:1.2");
+        assert_eq!(format!("{version:.5}"), "1:1.2.3");
+        let version = Version::parse("1.2.3");
+        assert_eq!(format!("{version:>10}"), "     1.2.3");
+        assert_eq!(format!("{version:10}|"), "1.2.3     |");
+        assert_eq!(format!("{version:*^9}"), "**1.2.3**");
+        assert_eq!(format!("{version:>6.2}"), "   1.2");
+        assert_eq!(format!("{version:3}"), "1.2.3");
+    }
+    #[test]
+    fn component_total_order() {
+        use crate::version::Component;
+        let big = "1".repeat(40);
+        let bigger = "2".repeat(40);
+        let max = u128::MAX.to_string();
+        // from lowest to greatest, two of each variant
+        let ascending = [
+            "dev1", "dev10", "0", &max, &big, &bigger, "post1", "post10", "a", "x",
+        ];
+        for (i, a) in ascending.iter().enumerate() {
+            for (j, b) in ascending.iter().enumerate() {
+                let expected = i.cmp(&j);
+                assert_eq!(Component::new(a).cmp(&Component::new(b)), expected, "{a} vs {b}");
+                assert_eq!(Version::parse(a).cmp(&Version::parse(b)), expected, "{a} vs {b}");
+                assert_eq!(Version::parse(a).sort_key().cmp(&Version::parse(b).sort_key()), expected, "{a} vs {b}");
+            }
+        }
+        assert_eq!(Component::new("007"), Component::Numeric(7));
+        assert_eq!(Component::new(&format!("0{big}")), Component::BigNumeric(&big));
+        assert_eq!(Component::new("dev007"), Component::Dev("7"));
+        assert_eq!(Component::new("post"), Component::Post(""));
+        assert_eq!(Component::new("1a"), Component::Alpha("1a"));
+    }
+    #[test]
+    fn filter_stable_and_sorted_desc() {
+        let versions = ["1.2.0", "2.0.0-rc.1", "1.10.0", "1.0.0~beta", "0.9.0", "1.10.0-SNAPSHOT"].map(Version::parse);
+        let stable = Version::filter_stable(versions.clone());
+        assert_eq!(stable, ["1.2.0", "1.10.0", "0.9.0"].map(Version::parse));
+        let sorted = Version::sorted_desc(versions.to_vec());
+        let expected = ["2.0.0-rc.1", "1.10.0", "1.10.0-SNAPSHOT", "1.2.0", "1.0.0~beta", "0.9.0"];
+        assert_eq!(sorted, expected.map(Version::parse));
+        let newest_stable = Version::sorted_desc(Version::filter_stable(versions));
+        assert_eq!(newest_stable.first(), Some(&Version::parse("1.10.0")));
+        assert!(Version::sorted_desc(Vec::new()).is_empty());
+    }
+    #[test]
+    fn parse_debian() {
+        let debian = |s| Version::parse_debian(s).unwrap();
+        assert!(debian("1.2.3-1") < debian("1.2.3-2"));
+        assert!(debian("1.2.3-1") > debian("1.2.3"));
+        assert!(debian("1.2.3-10") > debian("1.2.3-9"));
+        assert!(debian("1.2.3-1") < debian("1.2.4"));
+        assert!(debian("1.2.3~rc1-5") < debian("1.2.3-1"));
+        assert_ne!(debian("1.2.3-1"), Version::parse("1.2.3-1"));
+        assert_eq!(debian("1.2.3-1").sort_key().cmp(&debian("1.2.3").sort_key()), Greater);
+        let set: HashSet<Version> = [debian("1.2.3-1"), debian("1.2.3-2"), debian("1.2.3-1")].into();
+        assert_eq!(set.len(), 2);
+
+        let version = debian("1.2+dfsg-3");
+        assert_eq!(version.revision(), Some(3));
+        assert_eq!(version.build_metadata.as_deref(), Some("dfsg"));
+        assert_eq!(version.pre_release, None);
+        assert_eq!(version.to_string(), "1.2+dfsg-3");
+        assert_eq!(format!("{version:.1}"), "1");
+        assert_eq!(version.to_release().revision(), None);
+        assert_eq!(version.diff(&debian("1.2+dfsg-4")), VersionDiff::Revision);
+        assert!(version.to_semver_string().is_err());
+
+        for quoted in ["'1.2.3-1'", "\"1.2.3-1\"", " '1.2.3-1' "] {
+            let version = debian(quoted);
+            assert_eq!((version.pre_release.as_deref(), version.revision()), (None, Some(1)), "{quoted}");
+            assert_eq!(version, debian("1.2.3-1"));
+            assert_eq!(version.original(), Some(quoted));
+        }
+
+        // the string form is only read back as a revision by `parse_debian`
+        let version = debian("1:1.2.3~rc1-2");
+        let string = version.to_string();
+        assert_eq!(debian(&string), version);
+        let reparsed: Version = version.to_string().parse().unwrap();
+        assert_eq!((reparsed.pre_release.as_deref(), reparsed.revision()), (Some("rc1-2"), None));
+        assert_ne!(reparsed, version);
+
+        let version = debian("1.2.3-beta-2");
+        assert_eq!((version.pre_release.as_deref(), version.revision()), (Some("beta"), Some(2)));
+        let version = debian("1.2.3-beta");
+        assert_eq!((version.pre_release.as_deref(), version.revision()), (Some("beta"), None));
+        assert_eq!(Version::parse_debian("-1"), Err(ParseVersionError::NoComponents));
+    }
 }
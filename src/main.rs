@@ -1,18 +1,60 @@
+//! compares two versions.
+//!
+//! usage:
+//! - `h-version <version1> <version2>` prints how the two versions compare
+//! - `h-version --cmp <version1> <version2>` prints `-1`, `0` or `1`
+//! - `h-version --satisfies <requirement> <version>` prints nothing
+//!
+//! exit codes:
+//! - 0: success (for `--satisfies`: the version satisfies the requirement)
+//! - 1: the version does not satisfy the requirement (`--satisfies` only)
+//! - 64: wrong arguments
+//! - 65: invalid requirement or version
 use std::cmp::Ordering;
 use std::process::exit;
+use h_version::{Version, VersionReq};
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    if args.len() != 2{
-        println!("there must be two arguments");
-        exit(64);
+/// parses a version argument, or exits with code 65 and says which argument is invalid.
+fn parse_version(version_str: &str) -> Version {
+    match version_str.parse() {
+        Ok(version) => version,
+        Err(error) => {
+            eprintln!("invalid version \"{version_str}\": {error}");
+            exit(65);
+        }
     }
-    let version1 = args.next().unwrap();
-    let version2 = args.next().unwrap();
-    let operation = version1.cmp(&version2);
-    match operation {
-        Ordering::Equal => {println!("{version1} is equal to {version2}")}
-        Ordering::Less => {println!("{version1} is less than {version2}")}
-        Ordering::Greater => {println!("{version1} is greater than {version2}")}
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [flag, requirement, version] if flag == "--satisfies" => {
+            let requirement = match VersionReq::parse(requirement) {
+                Ok(requirement) => requirement,
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(65);
+                }
+            };
+            if !requirement.matches(&parse_version(version)) {
+                exit(1);
+            }
+        }
+        [flag, version1, version2] if flag == "--cmp" => {
+            let operation = parse_version(version1).cmp(&parse_version(version2));
+            println!("{}", operation as i8);
+        }
+        [version1, version2] => {
+            let operation = parse_version(version1).cmp(&parse_version(version2));
+            match operation {
+                Ordering::Equal => {println!("{version1} is equal to {version2}")}
+                Ordering::Less => {println!("{version1} is less than {version2}")}
+                Ordering::Greater => {println!("{version1} is greater than {version2}")}
+            }
+        }
+        _ => {
+            println!("there must be two arguments");
+            exit(64);
+        }
     }
-}
\ No newline at end of file
+}
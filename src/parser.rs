@@ -0,0 +1,78 @@
+/// the parts of a version string, borrowed from it. [`Version::parse`](crate::Version::parse)
+/// makes owned data from them, and [`cmp_raw`](crate::cmp_raw) compares them directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Tokens<'a> {
+    pub(crate) prefix: Option<char>, // `v` or `V` of git tags
+    pub(crate) epoch: Option<&'a str>, // before `:`, not checked
+    pub(crate) core: &'a str, // the main components, separated by `.` or `_`
+    pub(crate) pre_release: Option<&'a str>, // after `-` or `~`
+    pub(crate) tilde: bool, // the pre-release follows `~`
+    pub(crate) build_metadata: Option<&'a str>, // after `+`
+}
+impl<'a> Tokens<'a> {
+    pub(crate) fn components(&self) -> impl Iterator<Item = &'a str> {
+        self.core.split(['.', '_'])
+    }
+}
+
+/// strips whitespace and one layer of matching quotes (e.g., " 1.2.3 " or "'1.2.3'" from YAML).
+pub(crate) fn unquote(version_str: &str) -> &str {
+    let version_str = version_str.trim_ascii();
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| version_str.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(version_str)
+}
+
+/// splits a version string into its parts, or `None` if it is empty.
+pub(crate) fn tokenize(version_str: &str) -> Option<Tokens<'_>> {
+    let version_str = unquote(version_str);
+    if version_str.is_empty() {
+        return None;
+    }
+
+    // Strip the prefix of git tags (e.g., "v1.2.3"), but not the start of a word like "version1"
+    let prefix = version_str.chars().next().filter(|&c| {
+        matches!(c, 'v' | 'V') && version_str[1..].starts_with(|c: char| c.is_ascii_digit())
+    });
+    let version_str = if prefix.is_some() { &version_str[1..] } else { version_str };
+
+    // Handle epochs
+    let (epoch, rest) = match version_str.split_once(':') {
+        Some((epoch, rest)) => (Some(epoch), rest),
+        None => (None, version_str),
+    };
+
+    // Split into main version and build metadata (an empty one like in "1.0.0+" is none)
+    let (version_part, build_metadata) = match rest.split_once('+') {
+        Some((version_part, build_metadata)) => {
+            (version_part, Some(build_metadata).filter(|s| !s.is_empty()))
+        }
+        None => (rest, None),
+    };
+
+    // Split into main version and pre-release (after `-`, or after `~` like in Debian). an empty
+    // one like in "1.0.0-" is none
+    let (core, pre_release, tilde) = match version_part.find(['-', '~']) {
+        Some(i) if i + 1 == version_part.len() => (&version_part[..i], None, false),
+        Some(i) => (&version_part[..i], Some(&version_part[i + 1..]), version_part[i..].starts_with('~')),
+        None => (version_part, None, false),
+    };
+    Some(Tokens { prefix, epoch, core, pre_release, tilde, build_metadata })
+}
+
+/// the parts of a version string like [`Version::parse`](crate::Version::parse) reads it: the
+/// parts of the [`Default`] version if it is not valid.
+pub(crate) fn lenient_tokens(version_str: &str) -> Tokens<'_> {
+    tokenize(version_str)
+        .filter(|tokens| tokens.epoch.is_none_or(|epoch| epoch.parse::<u64>().is_ok()))
+        .filter(|tokens| tokens.components().any(|component| !component.is_empty()))
+        .unwrap_or(Tokens {
+            prefix: None,
+            epoch: None,
+            core: "0.0.1",
+            pre_release: None,
+            tilde: false,
+            build_metadata: None,
+        })
+}
@@ -0,0 +1,52 @@
+use core::ops::Range;
+use core::str::FromStr;
+use alloc::string::ToString;
+use crate::{ParseVersionError, Version};
+
+/// a half-open range of versions, like a Rust `Range`: the start is included, the end is not.
+/// # Example
+/// ```
+/// use h_version::{Version, VersionRange};
+/// let range = Version::range("1.2.0".."2.0.0");
+/// assert!(range.contains(&Version::parse("1.2.0")));
+/// assert!(!range.contains(&Version::parse("2.0.0")));
+/// assert_eq!("1.2.0..2.0.0".parse::<VersionRange>(), Ok(range));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRange {
+    pub start: Version, // the lowest version in the range
+    pub end: Version, // the first version after the range
+}
+impl VersionRange {
+    /// makes a range from a str like "1.2.0..2.0.0".
+    pub fn parse(range_str: &str) -> Result<Self, ParseVersionError> {
+        range_str.parse()
+    }
+    /// whether the version is in this range.
+    ///
+    /// build metadata is ignored, see [`Version::cmp_precedence`].
+    pub fn contains(&self, version: &Version) -> bool {
+        version.cmp_precedence(&self.start).is_ge() && version.cmp_precedence(&self.end).is_lt()
+    }
+}
+impl FromStr for VersionRange {
+    type Err = ParseVersionError;
+
+    fn from_str(range_str: &str) -> Result<Self, Self::Err> {
+        let (start, end) = range_str
+            .split_once("..")
+            .ok_or_else(|| ParseVersionError::BadRequirement(range_str.to_string()))?;
+        Ok(VersionRange {
+            start: start.parse()?,
+            end: end.parse()?,
+        })
+    }
+}
+impl From<Range<Version>> for VersionRange {
+    fn from(range: Range<Version>) -> Self {
+        VersionRange {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
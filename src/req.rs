@@ -0,0 +1,144 @@
+use core::cmp::Ordering;
+use core::str::FromStr;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use crate::version::is_number;
+use crate::{ParseVersionError, Version};
+
+/// a version requirement made of comma-separated comparators (e.g. ">=1.2.0, <2.0.0").
+///
+/// the supported operators are `=`, `>`, `>=`, `<`, `<=`, `^` and `~`, with the same meaning as
+/// in Cargo. a comparator without an operator is a caret requirement. the main components must
+/// be numbers, wildcards like "1.*" are not supported.
+///
+/// like in Cargo, a pre-release only matches if a comparator has a pre-release with the same
+/// epoch and main components, so ">=1.2.0" does not match "1.3.0-alpha" but ">=1.2.0-rc"
+/// matches "1.2.0-rc.1".
+/// # Example
+/// ```
+/// use h_version::{Version, VersionReq};
+/// let req = VersionReq::parse("^1.2.3").unwrap();
+/// assert!(req.matches(&Version::parse("1.5.0")));
+/// assert!(!req.matches(&Version::parse("2.0.0")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: Version,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Exact,     // =1.2.3
+    Greater,   // >1.2.3
+    GreaterEq, // >=1.2.3
+    Less,      // <1.2.3
+    LessEq,    // <=1.2.3
+    Caret,     // ^1.2.3
+    Tilde,     // ~1.2.3
+}
+impl VersionReq {
+    /// makes a requirement from a str like ">=1.2.0, <2.0.0".
+    pub fn parse(req_str: &str) -> Result<Self, ParseVersionError> {
+        req_str.parse()
+    }
+    /// whether the version satisfies every comparator of this requirement, and is not a
+    /// pre-release that the requirement does not ask for.
+    ///
+    /// build metadata is ignored, see [`Version::cmp_precedence`].
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|comparator| comparator.matches(version))
+            && (version.is_stable()
+                || self.comparators.iter().any(|comparator| comparator.allows_pre_release(version)))
+    }
+}
+impl FromStr for VersionReq {
+    type Err = ParseVersionError;
+
+    fn from_str(req_str: &str) -> Result<Self, Self::Err> {
+        let comparators = req_str
+            .split(',')
+            .map(str::trim)
+            .map(Comparator::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(VersionReq { comparators })
+    }
+}
+impl Comparator {
+    fn parse(comparator_str: &str) -> Result<Self, ParseVersionError> {
+        let (op, rest) = [
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("^", Op::Caret),
+            ("~", Op::Tilde),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| comparator_str.strip_prefix(prefix).map(|rest| (op, rest)))
+        .unwrap_or((Op::Caret, comparator_str));
+        let rest = rest.trim_start();
+        if rest.is_empty() {
+            return Err(ParseVersionError::BadRequirement(comparator_str.to_string()));
+        }
+        let comparator = Comparator {
+            op,
+            version: rest.parse()?,
+        };
+        // wildcards ("1.*") and whitespace ("1.0 <2.0", a missing comma) would never match
+        if !comparator.version.iter_components().all(is_number) {
+            return Err(ParseVersionError::BadRequirement(comparator_str.to_string()));
+        }
+        // partial versions and caret/tilde requirements need a numeric upper bound
+        let needs_upper = match op {
+            Op::Caret | Op::Tilde => true,
+            Op::Exact | Op::Greater | Op::LessEq => comparator.is_partial(),
+            Op::GreaterEq | Op::Less => false,
+        };
+        if needs_upper && comparator.upper().is_none() {
+            return Err(ParseVersionError::BadRequirement(comparator_str.to_string()));
+        }
+        Ok(comparator)
+    }
+    fn matches(&self, version: &Version) -> bool {
+        let cmp = version.cmp_precedence(&self.version);
+        match self.op {
+            Op::Exact if self.is_partial() => cmp != Ordering::Less && self.below_upper(version),
+            Op::Exact => cmp == Ordering::Equal,
+            Op::Greater if self.is_partial() => !self.below_upper(version),
+            Op::Greater => cmp == Ordering::Greater,
+            Op::GreaterEq => cmp != Ordering::Less,
+            Op::Less => cmp == Ordering::Less,
+            Op::LessEq if self.is_partial() => self.below_upper(version),
+            Op::LessEq => cmp != Ordering::Greater,
+            Op::Caret => version.satisfies_caret(&self.version),
+            Op::Tilde => version.satisfies_tilde(&self.version),
+        }
+    }
+    /// whether this comparator has a pre-release of the same release as the version
+    fn allows_pre_release(&self, version: &Version) -> bool {
+        self.version.is_prerelease()
+            && self.version.to_release().cmp_precedence(&version.to_release()) == Ordering::Equal
+    }
+    /// whether fewer than major, minor and patch are given (e.g. "=1.2" means "1.2.*")
+    fn is_partial(&self) -> bool {
+        self.version.components.len() < 3
+    }
+    fn below_upper(&self, version: &Version) -> bool {
+        self.upper()
+            .is_some_and(|upper| version.cmp_precedence(&upper) == Ordering::Less)
+    }
+    /// the first version that is too new for this comparator
+    fn upper(&self) -> Option<Version> {
+        match self.op {
+            Op::Caret => self.version.caret_upper(),
+            Op::Tilde => self.version.tilde_upper(),
+            // the last given component may not change (=1.2 means <1.3.0)
+            _ => self.version.bump(self.version.components.len().min(3) - 1),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/// the kind of a version, see [`Version::scheme`](crate::Version::scheme).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersionScheme {
+    /// every main component is a number (e.g. "1.2.3"), and it is not a calendar version
+    SemVer,
+    /// a calendar version (e.g. "2023.03.01"), see
+    /// [`Version::looks_like_calver`](crate::Version::looks_like_calver)
+    CalVer,
+    /// anything else (e.g. "2.sjf.5djf")
+    Unknown,
+}
+impl core::fmt::Display for VersionScheme {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VersionScheme::SemVer => write!(f, "SemVer"),
+            VersionScheme::CalVer => write!(f, "CalVer"),
+            VersionScheme::Unknown => write!(f, "unknown"),
+        }
+    }
+}
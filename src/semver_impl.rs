@@ -0,0 +1,46 @@
+use alloc::string::ToString;
+use crate::{ParseVersionError, Version};
+
+/// converts a version of the `semver` crate, whose parts are always valid here.
+impl From<semver::Version> for Version {
+    fn from(version: semver::Version) -> Self {
+        Version::new(
+            None,
+            [version.major, version.minor, version.patch].iter().map(u64::to_string).collect(),
+            (!version.pre.is_empty()).then(|| version.pre.to_string()),
+            (!version.build.is_empty()).then(|| version.build.to_string()),
+        )
+    }
+}
+
+/// converts to a version of the `semver` crate, if this version is a SemVer version (see
+/// [`Version::parse_semver`]) whose parts the `semver` crate accepts.
+impl TryFrom<&Version> for semver::Version {
+    type Error = ParseVersionError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        version.check_semver()?;
+        let number = |component: &str| {
+            component
+                .parse::<u64>()
+                .map_err(|_| ParseVersionError::NotSemVer(component.to_string()))
+        };
+        let pre = match version.pre_release.as_deref() {
+            Some(pre_release) => semver::Prerelease::new(pre_release)
+                .map_err(|_| ParseVersionError::NotSemVer(pre_release.to_string()))?,
+            None => semver::Prerelease::EMPTY,
+        };
+        let build = match version.build_metadata.as_deref() {
+            Some(build_metadata) => semver::BuildMetadata::new(build_metadata)
+                .map_err(|_| ParseVersionError::NotSemVer(build_metadata.to_string()))?,
+            None => semver::BuildMetadata::EMPTY,
+        };
+        Ok(semver::Version {
+            major: number(&version.components[0])?,
+            minor: number(&version.components[1])?,
+            patch: number(&version.components[2])?,
+            pre,
+            build,
+        })
+    }
+}
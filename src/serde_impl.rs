@@ -0,0 +1,32 @@
+use core::fmt::Formatter;
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::Version;
+
+/// serializes as the version string (e.g. "1:2.3.4-rc+5"), see [`Display`](core::fmt::Display).
+///
+/// the revision of a [`Version::parse_debian`] version is written too ("1.2.3-1"), but it is
+/// deserialized as a pre-release, see there.
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// deserializes from a version string, see [`FromStr`](core::str::FromStr).
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionVisitor;
+        impl Visitor<'_> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+                write!(f, "a version string")
+            }
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+        deserializer.deserialize_str(VersionVisitor)
+    }
+}
@@ -1,5 +1,16 @@
-use std::cmp::Ordering;
-use std::fmt::{Debug, Display};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::parser::{lenient_tokens, tokenize, unquote, Tokens};
+use crate::{
+    CompareError, ParseVersionError, ParseWarning, VersionBuilder, VersionDiff, VersionKey, VersionRange, VersionReq,
+    VersionScheme,
+};
 
 ///
 ///
@@ -12,66 +23,1467 @@ use std::fmt::{Debug, Display};
 /// let version2 = Version::parse("1:123.543.56-beta+002");
 /// assert_eq!(version1.cmp(&version2),Greater);
 /// ```
+/// versions can also be compared with version strings directly (which parses them every time):
+/// ```
+/// use h_version::Version;
+/// let version = Version::parse("1.2.3");
+/// assert!(version == "1.2.3");
+/// assert!(version < "2.0.0");
+/// assert!("1.10" > version);
+/// ```
+/// # Ordering
+/// versions are compared part by part, and the first part that differs decides:
+/// 1. the epoch (a missing epoch is 0)
+/// 2. the main components, from left to right (a missing component is "0"). like in Python
+///    (PEP 440), a "devN" component is lower than any number and a "postN" component is greater,
+///    so "1.2.3.dev1" < "1.2.3" < "1.2.3.post1"
+/// 3. the pre-release (a version without one is greater). a pre-release after `~` is lower than
+///    one after `-`, so "1.0.0~beta" < "1.0.0-alpha" < "1.0.0". like in Maven, a "SNAPSHOT"
+///    identifier is lower than any other, so "1.0.0-SNAPSHOT" < "1.0.0-alpha"
+/// 4. the Debian revision of [`Version::parse_debian`] (a version without one is lower), so
+///    "1.2.3" < "1.2.3-1" < "1.2.3-2"
+/// 5. the build metadata (a version without one is lower). [`Version::cmp_precedence`] skips
+///    this step, as SemVer does.
+///
+/// two versions are equal (`==`) exactly when they compare as equal, so "1.2" == "1.2.0".
+/// [`Version::cmp_strict_length`] tells them apart.
+///
+/// # Git tags
+/// a `v` or `V` prefix is stripped while parsing ("v1.2.3" is "1.2.3"). it is remembered,
+/// and the alternate form of [`Display`] shows it again:
+/// ```
+/// use h_version::Version;
+/// let version = Version::parse("v1.2.3");
+/// assert_eq!(version, Version::parse("1.2.3"));
+/// assert_eq!(format!("{version}"), "1.2.3");
+/// assert_eq!(format!("{version:#}"), "v1.2.3");
+/// ```
+///
+/// # Formatting
+/// a precision keeps only that many main components and drops the pre-release and build
+/// metadata, and a width pads the version like a string (left-aligned by default):
+/// ```
+/// use h_version::Version;
+/// let version = Version::parse("1.2.3-rc");
+/// assert_eq!(format!("{version:.2}"), "1.2");
+/// assert_eq!(format!("[{version:>10}]"), "[  1.2.3-rc]");
+/// ```
+///
+/// # Separators
+/// main components are separated by `.` or `_` ("1_2_3" is "1.2.3" and is displayed with `.`).
+/// the pre-release follows `-`, or `~` for Debian style pre-releases.
+#[derive(Clone)]
 pub struct Version {
     pub epoch: Option<u64>, // epochs (e.g., "1:2.3.4")
     pub components: Vec<String>, // Main version components (e.g., 1.2.3)
     pub pre_release: Option<String>, // Pre-release tag (e.g., "alpha", "beta", "Snapshot", "rc")
     pub build_metadata: Option<String>, // Build metadata (e.g., "+001")
+    prefix: Option<char>, // `v` or `V` prefix of git tags (e.g., "v1.2.3"), only shown by `{:#}`
+    tilde: bool, // the pre-release follows `~` instead of `-` (e.g., "1.0.0~beta")
+    original: Option<String>, // the string this version was parsed from
+    revision: Option<u64>, // Debian revision (e.g., the "1" of "1.2.3-1"), see `Version::parse_debian`
 }
 impl Version {
+    /// makes a version from its parts. an empty pre-release or build metadata is none, like when
+    /// parsing "1.0.0-" or "1.0.0+".
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::new(Some(1), vec!["2".to_string(), "3".to_string()], Some("rc".to_string()), None);
+    /// assert_eq!(version.to_string(), "1:2.3-rc");
+    /// ```
+    pub fn new(
+        epoch: Option<u64>,
+        components: Vec<String>,
+        pre_release: Option<String>,
+        build_metadata: Option<String>,
+    ) -> Self {
+        Version {
+            epoch,
+            components,
+            pre_release: pre_release.filter(|s| !s.is_empty()),
+            build_metadata: build_metadata.filter(|s| !s.is_empty()),
+            prefix: None,
+            tilde: false,
+            original: None,
+            revision: None,
+        }
+    }
+    /// makes a `major.minor.patch` version.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::from_numbers(1, 2, 3).to_string(), "1.2.3");
+    /// ```
+    pub fn from_numbers(major: u64, minor: u64, patch: u64) -> Self {
+        Version::new(
+            None,
+            vec![major.to_string(), minor.to_string(), patch.to_string()],
+            None,
+            None,
+        )
+    }
+    /// the version "0.0.0".
+    ///
+    /// this is not the [`Default`] version, which is "0.0.1".
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::zero().to_string(), "0.0.0");
+    /// ```
+    pub fn zero() -> Self {
+        Version::from_numbers(0, 0, 0)
+    }
+    /// starts building a version part by part, see [`VersionBuilder`].
+    pub fn builder() -> VersionBuilder {
+        VersionBuilder::default()
+    }
     /// makes a version from a str.
+    ///
+    /// this never fails. empty components are dropped ("1..3" is "1.3") and if the string is
+    /// still not a valid version (see [`FromStr`]), the [`Default`] version is returned instead.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1:23423.553.845-rc+255");
+    /// let version = version.to_string();
+    /// assert_eq!(version,"1:23423.553.845-rc+255".to_string());
+    pub fn parse(version_str: &str) -> Self {
+        Version::parse_with(version_str, true, None).unwrap_or_default()
+    }
+    /// makes a version from a str with [`FromStr`], or returns `fallback` if it is not valid.
+    ///
+    /// unlike [`Version::parse`], nothing is dropped to make the str valid.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let fallback = Version::from_numbers(1, 0, 0);
+    /// assert_eq!(Version::parse_or("1..3", fallback.clone()), fallback);
+    /// assert_eq!(Version::parse_or("1.3", fallback).to_string(), "1.3");
+    /// ```
+    pub fn parse_or(version_str: &str, fallback: Version) -> Self {
+        version_str.parse().unwrap_or(fallback)
+    }
+    /// makes a version from a str with [`FromStr`], or returns the [`Default`] version if it is
+    /// not valid. see [`Version::parse_or`].
+    pub fn parse_or_default(version_str: &str) -> Self {
+        version_str.parse().unwrap_or_default()
+    }
+    /// makes a version from a str like [`Version::parse`], and also tells what was changed or
+    /// dropped to accept it, see [`ParseWarning`].
+    ///
+    /// unlike [`Version::parse`], an invalid epoch is dropped instead of returning the
+    /// [`Default`] version.
+    /// # Example
+    /// ```
+    /// use h_version::{ParseWarning, Version};
+    /// let (version, warnings) = Version::parse_verbose("bad:1.2.3");
+    /// assert_eq!(version.to_string(), "1.2.3");
+    /// assert_eq!(warnings, [ParseWarning::EpochDropped("bad".to_string())]);
+    /// ```
+    pub fn parse_verbose(version_str: &str) -> (Self, Vec<ParseWarning>) {
+        let mut warnings = Vec::new();
+        let version = Version::parse_with(version_str, true, Some(&mut warnings)).unwrap_or_else(|error| {
+            warnings.push(ParseWarning::Defaulted(error));
+            Version::default()
+        });
+        (version, warnings)
+    }
+    /// makes a half-open range from two version strs, parsed with [`Version::parse`].
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let range = Version::range("1.2.0".."2.0.0");
+    /// assert!(range.contains(&Version::parse("1.9.9")));
+    /// ```
+    pub fn range(range: core::ops::Range<&str>) -> VersionRange {
+        VersionRange::from(Version::parse(range.start)..Version::parse(range.end))
+    }
+    /// makes a version from a str that must be a canonical SemVer version:
+    /// `MAJOR.MINOR.PATCH` with an optional `-pre_release` and `+build_metadata`. unlike
+    /// [`FromStr`], no prefix, quotes, surrounding whitespace, `_` or `~` are accepted.
+    /// # Example
+    /// ```
+    /// use h_version::{ParseVersionError, Version};
+    /// assert!(Version::parse_semver("1.2.3-rc+5").is_ok());
+    /// assert_eq!(Version::parse_semver("1.2"), Err(ParseVersionError::WrongComponentCount(2)));
+    /// ```
+    pub fn parse_semver(version_str: &str) -> Result<Version, ParseVersionError> {
+        let version: Version = version_str.parse()?;
+        version.check_semver()?;
+        // the parser forgives what SemVer does not allow, so the raw str is checked too: an
+        // empty pre-release or build metadata ("1.2.3-") is read as none, and a prefix, quotes,
+        // whitespace and `_` are not part of the components
+        let (rest, build_metadata) = match version_str.split_once('+') {
+            Some((rest, build_metadata)) => (rest, Some(build_metadata)),
+            None => (version_str, None),
+        };
+        let (core, pre_release) = match rest.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (rest, None),
+        };
+        if !core.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+            return Err(ParseVersionError::NotSemVer(core.to_string()));
+        }
+        for part in [pre_release, build_metadata].into_iter().flatten() {
+            check_semver_identifiers(part)?;
+        }
+        Ok(version)
+    }
+    /// parses a Debian package version like [`FromStr`], but a final `-N` (a number) is the
+    /// Debian revision instead of a pre-release. it is compared right after the pre-release, and
+    /// a version with a revision is greater than the same version without one.
+    ///
+    /// the revision is written at the end of the string form ("1.2.3-1"), which only this
+    /// function reads back: [`FromStr`] and `Deserialize` read it as the pre-release "1", so the
+    /// version they give is lower than "1.2.3". store Debian versions as strings and parse them
+    /// with this function to keep their order.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse_debian("1:1.2.3~rc1-2").unwrap();
+    /// assert_eq!(version.revision(), Some(2));
+    /// assert_eq!(version.pre_release.as_deref(), Some("rc1"));
+    /// assert_eq!(version.to_string(), "1:1.2.3~rc1-2");
+    /// assert!(Version::parse_debian("1.2.3-1").unwrap() > Version::parse("1.2.3"));
+    /// ```
+    pub fn parse_debian(version_str: &str) -> Result<Version, ParseVersionError> {
+        // quotes are stripped first, or the revision of "'1.2.3-1'" would be "1'"
+        let trimmed = unquote(version_str);
+        let (upstream, revision) = match trimmed.rsplit_once('-') {
+            Some((upstream, revision)) if !upstream.is_empty() && is_number(revision) => match revision.parse() {
+                Ok(revision) => (upstream, Some(revision)),
+                Err(_) => (trimmed, None),
+            },
+            _ => (trimmed, None),
+        };
+        let mut version: Version = upstream.parse()?;
+        version.revision = revision;
+        version.original = Some(version_str.to_string());
+        Ok(version)
+    }
+    /// the Debian revision, only found by [`Version::parse_debian`] (e.g. 1 for "1.2.3-1").
+    pub fn revision(&self) -> Option<u64> {
+        self.revision
+    }
+    /// fails like [`Version::parse_semver`] if this is not a canonical SemVer version
+    pub(crate) fn check_semver(&self) -> Result<(), ParseVersionError> {
+        if self.epoch.is_some() {
+            return Err(ParseVersionError::HasEpoch);
+        }
+        if let Some(revision) = self.revision {
+            return Err(ParseVersionError::NotSemVer(format!("-{revision}")));
+        }
+        // a `~` pre-release sorts differently, SemVer only has `-` ones
+        if let Some(pre_release) = self.pre_release.as_deref().filter(|_| self.tilde) {
+            return Err(ParseVersionError::NotSemVer(format!("~{pre_release}")));
+        }
+        if self.components.len() != 3 {
+            return Err(ParseVersionError::WrongComponentCount(self.components.len()));
+        }
+        for component in &self.components {
+            if !component.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseVersionError::NonNumericComponent(component.clone()));
+            }
+            if has_leading_zero(component) {
+                return Err(ParseVersionError::LeadingZero(component.clone()));
+            }
+        }
+        // numeric pre-release identifiers can not have leading zeros either ("alpha.01")
+        let mut identifiers = self.pre_release.iter().flat_map(|pre_release| pre_release.split('.'));
+        if let Some(identifier) = identifiers.find(|identifier| has_leading_zero(identifier)) {
+            return Err(ParseVersionError::LeadingZero(identifier.to_string()));
+        }
+        for part in [&self.pre_release, &self.build_metadata].into_iter().flatten() {
+            check_semver_identifiers(part)?;
+        }
+        Ok(())
+    }
+    /// compares two versions by SemVer precedence, which ignores build metadata.
+    ///
+    /// [`Ord`] uses the build metadata as a last tie-breaker instead.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version1 = Version::parse("1.0.0+001");
+    /// let version2 = Version::parse("1.0.0+002");
+    /// assert_eq!(version1.cmp_precedence(&version2), Ordering::Equal);
+    /// assert_eq!(version1.cmp(&version2), Ordering::Less);
+    /// ```
+    pub fn cmp_precedence(&self, other: &Version) -> Ordering {
+        self.cmp_epoch(other)
+            .then_with(|| self.cmp_components(other))
+            .then_with(|| self.cmp_pre_release(other))
+            .then_with(|| self.cmp_revision(other))
+    }
+    /// compares like [`Ord`], but without the epochs, to tell whether the upstream version of a
+    /// repackaged one is newer.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version1 = Version::parse("2:1.2.3");
+    /// let version2 = Version::parse("1.2.4");
+    /// assert_eq!(version1.cmp(&version2), Ordering::Greater);
+    /// assert_eq!(version1.cmp_ignore_epoch(&version2), Ordering::Less);
+    /// ```
+    pub fn cmp_ignore_epoch(&self, other: &Version) -> Ordering {
+        self.cmp_components(other)
+            .then_with(|| self.cmp_pre_release(other))
+            .then_with(|| self.cmp_revision(other))
+            .then_with(|| self.cmp_build_metadata(other))
+    }
+    /// the pre-release ranks used by [`Version::cmp_ranked`] in most release conventions.
+    pub const PRE_RELEASE_RANKS: &'static [(&'static str, u32)] =
+        &[("alpha", 1), ("beta", 2), ("milestone", 3), ("rc", 4), ("snapshot", 5)];
+    /// compares like [`Ord`], but pre-release tags are ordered by their rank in `ranks`
+    /// (e.g. [`Version::PRE_RELEASE_RANKS`]) instead of lexically.
+    ///
+    /// a tag is the leading letters of a pre-release identifier and is found ignoring case, so
+    /// with `("m", 3)` in the table, "m1" and "M2" are milestones. identifiers with the same tag
+    /// are compared by what follows it ("m2" < "m10"). ranked identifiers are greater than
+    /// numeric ones and lower than ones with an unknown tag, which are compared as usual. a
+    /// "SNAPSHOT" that is not in `ranks` stays lower than any other identifier.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let snapshot = Version::parse("1.0.0-snapshot");
+    /// let rc = Version::parse("1.0.0-rc");
+    /// assert_eq!(snapshot.cmp(&rc), Ordering::Less);
+    /// let ranks = [("rc", 1), ("snapshot", 2)];
+    /// assert_eq!(snapshot.cmp_ranked(&rc, &ranks), Ordering::Greater);
+    /// ```
+    pub fn cmp_ranked(&self, other: &Version, ranks: &[(&str, u32)]) -> Ordering {
+        self.cmp_epoch(other)
+            .then_with(|| self.cmp_components(other))
+            .then_with(|| self.cmp_pre_release_by(other, |a, b| cmp_ranked_identifier(a, b, ranks)))
+            .then_with(|| self.cmp_revision(other))
+            .then_with(|| self.cmp_build_metadata(other))
+    }
+    /// compares like [`Ord`], but if the main components are equal except for a tail of zeros,
+    /// the version with more components is greater ("1.2.0.0" > "1.2").
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version1 = Version::parse("1.2.0.0");
+    /// let version2 = Version::parse("1.2");
+    /// assert_eq!(version1.cmp(&version2), Ordering::Equal);
+    /// assert_eq!(version1.cmp_strict_length(&version2), Ordering::Greater);
+    /// ```
+    pub fn cmp_strict_length(&self, other: &Version) -> Ordering {
+        self.cmp_epoch(other)
+            .then_with(|| self.cmp_components(other))
+            .then_with(|| self.components.len().cmp(&other.components.len()))
+            .then_with(|| self.cmp_pre_release(other))
+            .then_with(|| self.cmp_revision(other))
+            .then_with(|| self.cmp_build_metadata(other))
+    }
+    /// compares like [`Ord`], but main components are compared with `component_cmp`. a missing
+    /// component is given to it as "0".
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// // components in hexadecimal
+    /// let hex = |a: &str, b: &str| u64::from_str_radix(a, 16).ok().cmp(&u64::from_str_radix(b, 16).ok());
+    /// assert_eq!(Version::parse("1.a").cmp_by(&Version::parse("1.9"), hex), Ordering::Greater);
+    /// ```
+    pub fn cmp_by<F>(&self, other: &Version, component_cmp: F) -> Ordering
+    where
+        F: Fn(&str, &str) -> Ordering,
+    {
+        self.cmp_epoch(other)
+            .then_with(|| self.cmp_components_by(other, component_cmp))
+            .then_with(|| self.cmp_pre_release(other))
+            .then_with(|| self.cmp_revision(other))
+            .then_with(|| self.cmp_build_metadata(other))
+    }
+    // Compare epochs (a missing epoch is epoch 0, like in Debian)
+    fn cmp_epoch(&self, other: &Version) -> Ordering {
+        self.epoch_or_zero().cmp(&other.epoch_or_zero())
+    }
+    // Compare main components (a missing component counts as "0", so 1.2 == 1.2.0)
+    fn cmp_components(&self, other: &Version) -> Ordering {
+        self.cmp_components_by(other, cmp_component)
+    }
+    fn cmp_components_by(
+        &self,
+        other: &Version,
+        component_cmp: impl Fn(&str, &str) -> Ordering,
+    ) -> Ordering {
+        let len = self.components.len().max(other.components.len());
+        (0..len)
+            .map(|i| {
+                let a = self.components.get(i).map_or("0", |s| s.as_str());
+                let b = other.components.get(i).map_or("0", |s| s.as_str());
+                component_cmp(a, b)
+            })
+            .find(|cmp| cmp.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+    // Compare pre-releases
+    fn cmp_pre_release(&self, other: &Version) -> Ordering {
+        self.cmp_pre_release_by(other, cmp_identifier)
+    }
+    fn cmp_pre_release_by(
+        &self,
+        other: &Version,
+        identifier_cmp: impl Fn(&str, &str) -> Ordering,
+    ) -> Ordering {
+        match (&self.pre_release, &other.pre_release) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater, // No pre-release is greater
+            (Some(_), None) => Ordering::Less, // Pre-release is less
+            // `~` pre-releases are lower than `-` ones
+            (Some(a), Some(b)) => other
+                .tilde
+                .cmp(&self.tilde)
+                .then_with(|| cmp_identifiers_by(a, b, identifier_cmp)),
+        }
+    }
+    // compare Debian revisions (a version without one is lower)
+    fn cmp_revision(&self, other: &Version) -> Ordering {
+        self.revision.cmp(&other.revision)
+    }
+    // compare build metadata
+    fn cmp_build_metadata(&self, other: &Version) -> Ordering {
+        self.build_metadata.cmp(&other.build_metadata)
+    }
+    /// the first component as a number, or `None` if it is missing or not numeric.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3");
+    /// assert_eq!(version.major(), Some(1));
+    /// assert_eq!(version.minor(), Some(2));
+    /// assert_eq!(version.patch(), Some(3));
+    /// ```
+    pub fn major(&self) -> Option<u64> {
+        self.numeric_component(0)
+    }
+    /// the second component as a number, or `None` if it is missing or not numeric.
+    pub fn minor(&self) -> Option<u64> {
+        self.numeric_component(1)
+    }
+    /// the third component as a number, or `None` if it is missing or not numeric.
+    pub fn patch(&self) -> Option<u64> {
+        self.numeric_component(2)
+    }
+    /// the epoch, or 0 if there is none (like in Debian).
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1:2.3.4").epoch_or_zero(), 1);
+    /// assert_eq!(Version::parse("2.3.4").epoch_or_zero(), 0);
+    /// ```
+    pub fn epoch_or_zero(&self) -> u64 {
+        self.epoch.unwrap_or(0)
+    }
+    /// whether an epoch was given, even "0:".
+    pub fn has_epoch(&self) -> bool {
+        self.epoch.is_some()
+    }
+    fn numeric_component(&self, index: usize) -> Option<u64> {
+        self.components.get(index)?.parse().ok()
+    }
+    /// the next major version. lower components are reset to "0" and pre-release
+    /// and build metadata are dropped.
+    ///
+    /// returns `None` if the major component is not numeric, or is already `u64::MAX`: bumping
+    /// never panics or wraps, and saturating would return a "next" version equal to this one.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3-alpha");
+    /// assert_eq!(version.bump_major().unwrap().to_string(), "2.0.0");
+    /// assert_eq!(version.bump_minor().unwrap().to_string(), "1.3.0");
+    /// assert_eq!(version.bump_patch().unwrap().to_string(), "1.2.4");
+    /// ```
+    pub fn bump_major(&self) -> Option<Version> {
+        self.bump(0)
+    }
+    /// the next minor version. see [`Version::bump_major`].
+    pub fn bump_minor(&self) -> Option<Version> {
+        self.bump(1)
+    }
+    /// the next patch version. see [`Version::bump_major`].
+    pub fn bump_patch(&self) -> Option<Version> {
+        self.bump(2)
+    }
+    /// the next pre-release: the last pre-release identifier is incremented if it is a number
+    /// ("rc.1" becomes "rc.2"), an empty one becomes "1" ("rc." becomes "rc.1"), else ".1" is
+    /// added ("alpha" becomes "alpha.1"). build metadata is dropped.
+    ///
+    /// returns `None` if this is not a pre-release, or if the number is already `u64::MAX`.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.2.0-rc.1").bump_prerelease().unwrap().to_string(), "1.2.0-rc.2");
+    /// assert_eq!(Version::parse("1.2.0-beta").bump_prerelease().unwrap().to_string(), "1.2.0-beta.1");
+    /// assert_eq!(Version::parse("1.2.0").bump_prerelease(), None);
+    /// ```
+    pub fn bump_prerelease(&self) -> Option<Version> {
+        let pre_release = self.pre_release.as_deref()?;
+        let pre_release = match pre_release.rsplit_once('.') {
+            Some((head, last)) if is_number(last) => {
+                format!("{head}.{}", last.parse::<u64>().ok()?.checked_add(1)?)
+            }
+            Some((head, "")) => format!("{head}.1"),
+            None if is_number(pre_release) => pre_release.parse::<u64>().ok()?.checked_add(1)?.to_string(),
+            _ => format!("{pre_release}.1"),
+        };
+        let mut version = Version::new(self.epoch, self.components.clone(), Some(pre_release), None);
+        version.tilde = self.tilde;
+        Some(version)
+    }
+    pub(crate) fn bump(&self, index: usize) -> Option<Version> {
+        let mut components = self.components.clone();
+        if components.len() <= index {
+            components.resize(index + 1, "0".to_string());
+        }
+        let bumped = components[index].parse::<u64>().ok()?.checked_add(1)?;
+        components[index] = bumped.to_string();
+        for component in &mut components[index + 1..] {
+            *component = "0".to_string();
+        }
+        Some(Version::new(self.epoch, components, None, None))
+    }
+    /// whether every main component is 0 (e.g. "0.0.0" or "0.00").
+    pub fn is_zero(&self) -> bool {
+        self.iter_components()
+            .all(|component| !component.is_empty() && component.bytes().all(|b| b == b'0'))
+    }
+    /// whether this is a version of the initial development (the major component is 0), where
+    /// SemVer allows anything to change at any time.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert!(Version::parse("0.5.0").is_initial_development());
+    /// assert!(!Version::parse("1.0.0").is_initial_development());
+    /// ```
+    pub fn is_initial_development(&self) -> bool {
+        self.major() == Some(0)
+    }
+    /// whether this version has a pre-release tag (e.g. "1.0.0-alpha").
+    pub fn is_prerelease(&self) -> bool {
+        self.pre_release.is_some()
+    }
+    /// whether this version has no pre-release tag.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let versions = vec![Version::parse("1.0.0"), Version::parse("1.1.0-rc"), Version::parse("1.0.1")];
+    /// let stable: Vec<Version> = versions.into_iter().filter(Version::is_stable).collect();
+    /// assert_eq!(stable, vec![Version::parse("1.0.0"), Version::parse("1.0.1")]);
+    /// ```
+    pub fn is_stable(&self) -> bool {
+        !self.is_prerelease()
+    }
+    /// the shared parser behind [`Version::parse`] (`lenient`) and [`FromStr`].
+    ///
+    /// in lenient mode, empty components are dropped instead of being rejected.
+    /// `warnings` is only given by [`Version::parse_verbose`], which also drops invalid epochs
+    fn parse_with(
+        version_str: &str,
+        lenient: bool,
+        mut warnings: Option<&mut Vec<ParseWarning>>,
+    ) -> Result<Self, ParseVersionError> {
+        let original = version_str;
+        let tokens = tokenize(version_str).ok_or(ParseVersionError::Empty)?;
+        if let (Some(prefix), Some(warnings)) = (tokens.prefix, warnings.as_deref_mut()) {
+            warnings.push(ParseWarning::PrefixStripped(prefix));
+        }
+
+        // Handle epochs
+        let epoch = match tokens.epoch {
+            Some(epoch) => match epoch.parse::<u64>() {
+                Ok(epoch) => Some(epoch),
+                Err(_) => match warnings.as_deref_mut() {
+                    Some(warnings) => {
+                        warnings.push(ParseWarning::EpochDropped(epoch.to_string()));
+                        None
+                    }
+                    None => return Err(ParseVersionError::BadEpoch(epoch.to_string())),
+                },
+            },
+            None => None,
+        };
+        let pre_release = tokens.pre_release.map(|s| s.to_string());
+        let build_metadata = tokens.build_metadata.map(|s| s.to_string());
+
+        // Split main version into components
+        if tokens.core.is_empty() {
+            return Err(ParseVersionError::NoComponents);
+        }
+        let mut components: Vec<String> = tokens.components().map(|s| s.to_string()).collect();
+        if lenient {
+            let count = components.len();
+            components.retain(|component| !component.is_empty());
+            if let Some(warnings) = warnings.filter(|_| components.len() < count) {
+                warnings.push(ParseWarning::EmptyComponentsDropped(count - components.len()));
+            }
+            if components.is_empty() {
+                return Err(ParseVersionError::NoComponents);
+            }
+        } else if components.iter().any(|component| component.is_empty()) {
+            return Err(ParseVersionError::EmptyComponent);
+        }
+
+        let mut version = Version::new(epoch, components, pre_release, build_metadata);
+        version.prefix = tokens.prefix;
+        version.tilde = tokens.tilde;
+        version.original = Some(original.to_string());
+        Ok(version)
+    }
+    /// the number of main components.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3");
+    /// assert_eq!(version.len(), 3);
+    /// assert_eq!(version.get_component(1), Some("2"));
+    /// assert_eq!(version.get_component(3), None);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    /// whether there are no main components (only possible with [`Version::new`]).
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+    /// the main component at `index`, or `None` if there are not that many.
+    pub fn get_component(&self, index: usize) -> Option<&str> {
+        self.components.get(index).map(String::as_str)
+    }
+    /// iterates over the main components.
+    pub fn iter_components(&self) -> impl Iterator<Item = &str> {
+        self.components.iter().map(String::as_str)
+    }
+    /// the main components as numbers, `None` for the non-numeric ones.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("2.sjf.5djf").numeric_components(), [Some(2), None, None]);
+    /// ```
+    pub fn numeric_components(&self) -> Vec<Option<u64>> {
+        self.iter_components().map(|component| component.parse().ok()).collect()
+    }
+    /// the identifiers of the pre-release (e.g. `["rc", "1"]` for "1.0.0-rc.1"), or none if
+    /// this is not a pre-release.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.0.0-rc.1").pre_release_identifiers(), ["rc", "1"]);
+    /// ```
+    pub fn pre_release_identifiers(&self) -> Vec<&str> {
+        self.pre_release.as_deref().map_or_else(Vec::new, |pre_release| pre_release.split('.').collect())
+    }
+    /// the first pre-release identifier (e.g. "rc" for "1.0.0-rc.2"), or `None` if this is not a
+    /// pre-release.
+    pub fn prerelease_tag(&self) -> Option<&str> {
+        self.pre_release.as_deref()?.split('.').next()
+    }
+    /// whether the first pre-release identifier is `tag`, ignoring case.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert!(Version::parse("1.0.0-rc.2").is_prerelease_tag("RC"));
+    /// ```
+    pub fn is_prerelease_tag(&self, tag: &str) -> bool {
+        self.prerelease_tag().is_some_and(|own| own.eq_ignore_ascii_case(tag))
+    }
+    /// whether this looks like a calendar version (e.g. "2023.03.01"): the first component is a
+    /// four digit year between 1900 and 2100.
+    ///
+    /// calendar versions need no special comparison: components are compared numerically, so
+    /// zero-padding does not matter ("2023.03.01" == "2023.3.1").
+    pub fn looks_like_calver(&self) -> bool {
+        self.components.first().is_some_and(|year| {
+            year.len() == 4 && year.parse::<u16>().is_ok_and(|year| (1900..=2100).contains(&year))
+        })
+    }
+    /// a copy of this version without pre-release and build metadata.
+    pub fn to_release(&self) -> Version {
+        Version::new(self.epoch, self.components.clone(), None, None)
+    }
+    /// the stable release this version is a candidate for: the same as [`Version::to_release`].
+    /// unlike [`Version::bump_patch`] and co., nothing is incremented.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.2.0-rc.1").release_target().to_string(), "1.2.0");
+    /// ```
+    pub fn release_target(&self) -> Version {
+        self.to_release()
+    }
+    /// the epoch and main components only.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1:2.3.4-rc+5").core_string(), "1:2.3.4");
+    /// ```
+    pub fn core_string(&self) -> String {
+        self.to_release().to_string()
+    }
+    /// the first `depth` main components joined with ".", or all of them if there are fewer. it
+    /// is useful to group versions by major or major.minor.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3-rc");
+    /// assert_eq!(version.family_key(1), "1");
+    /// assert_eq!(version.family_key(2), "1.2");
+    /// ```
+    pub fn family_key(&self, depth: usize) -> String {
+        self.components[..depth.min(self.components.len())].join(".")
+    }
+    /// compares this version with a version string, parsed with [`Version::parse`].
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3");
+    /// assert_eq!(version.cmp_str("1.10.0"), Ordering::Less);
+    /// assert!(version.eq_str("1.2.3"));
+    /// ```
+    pub fn cmp_str(&self, other: &str) -> Ordering {
+        self.cmp(&Version::parse(other))
+    }
+    /// whether this version equals a version string, parsed with [`Version::parse`].
+    pub fn eq_str(&self, other: &str) -> bool {
+        *self == Version::parse(other)
+    }
+    /// the greatest version of a slice, or `None` if it is empty.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let versions = [Version::parse("1.2.0"), Version::parse("2.0.0-rc"), Version::parse("1.10.0")];
+    /// assert_eq!(Version::latest(&versions), Some(&versions[1]));
+    /// assert_eq!(Version::latest_stable(&versions), Some(&versions[2]));
+    /// ```
+    pub fn latest(versions: &[Version]) -> Option<&Version> {
+        versions.iter().max()
+    }
+    /// the greatest version of a slice that is not a pre-release.
+    pub fn latest_stable(versions: &[Version]) -> Option<&Version> {
+        versions.iter().filter(|version| version.is_stable()).max()
+    }
+    /// the versions that are not pre-releases, in their original order.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let versions = ["1.0.0", "1.1.0-rc", "1.0.1"].map(Version::parse);
+    /// let newest = Version::sorted_desc(Version::filter_stable(versions));
+    /// assert_eq!(newest.first(), Some(&Version::parse("1.0.1")));
+    /// ```
+    pub fn filter_stable(versions: impl IntoIterator<Item = Version>) -> Vec<Version> {
+        versions.into_iter().filter(Version::is_stable).collect()
+    }
+    /// the versions sorted from greatest to lowest.
+    pub fn sorted_desc(mut versions: Vec<Version>) -> Vec<Version> {
+        versions.sort_by(|a, b| b.cmp(a));
+        versions
+    }
+    /// the greatest version of a slice that matches the requirement.
+    /// # Example
+    /// ```
+    /// use h_version::{Version, VersionReq};
+    /// let versions = [Version::parse("1.2.0"), Version::parse("1.9.0"), Version::parse("2.0.0")];
+    /// let req = VersionReq::parse("^1.2.0").unwrap();
+    /// assert_eq!(Version::max_satisfying(&versions, &req), Some(&versions[1]));
+    /// assert_eq!(Version::min_satisfying(&versions, &req), Some(&versions[0]));
+    /// ```
+    pub fn max_satisfying<'a>(versions: &'a [Version], req: &VersionReq) -> Option<&'a Version> {
+        versions.iter().filter(|version| req.matches(version)).max()
+    }
+    /// the lowest version of a slice that matches the requirement, see [`Version::max_satisfying`].
+    pub fn min_satisfying<'a>(versions: &'a [Version], req: &VersionReq) -> Option<&'a Version> {
+        versions.iter().filter(|version| req.matches(version)).min()
+    }
+    /// this version if it is between `low` and `high` (both included), else the nearest of them.
+    ///
+    /// for two versions, [`Ord::min`] and [`Ord::max`] work as usual.
+    ///
+    /// # Panics
+    /// if `low` is greater than `high`, like [`Ord::clamp`].
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let low = Version::parse("1.0.0");
+    /// let high = Version::parse("2.0.0");
+    /// assert_eq!(Version::parse("3.0.0").clamp_version(&low, &high), high);
+    /// assert_eq!(low.clone().max(high.clone()), high);
+    /// assert_eq!(low.clone().min(high), low);
+    /// ```
+    pub fn clamp_version(self, low: &Version, high: &Version) -> Version {
+        assert!(low <= high, "low must not be greater than high");
+        if self < *low {
+            low.clone()
+        } else if self > *high {
+            high.clone()
+        } else {
+            self
+        }
+    }
+    /// a copy of this version with "0" components added until there are `width` of them.
+    /// extra components are kept.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.2").normalized(3).to_string(), "1.2.0");
+    /// assert_eq!(Version::parse("1.2.3.4").normalized(3).to_string(), "1.2.3.4");
+    /// ```
+    pub fn normalized(&self, width: usize) -> Version {
+        let mut version = self.clone();
+        if version.components.len() < width {
+            version.components.resize(width, "0".to_string());
+            version.original = None;
+        }
+        version
+    }
+    /// a copy of this version without leading zeros in numeric main components ("1.02.3" is
+    /// "1.2.3", but "0" stays "0"). other components and the pre-release are kept as they are.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("2023.03.01").normalize_numeric().to_string(), "2023.3.1");
+    /// ```
+    pub fn normalize_numeric(&self) -> Version {
+        let mut version = self.clone();
+        for component in &mut version.components {
+            if has_leading_zero(component) {
+                *component = strip_leading_zeros(component).to_string();
+                version.original = None;
+            }
+        }
+        version
+    }
+    /// a copy of this version without trailing "0" components, keeping at least one. this is
+    /// the opposite of [`Version::normalized`].
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.2.0.0-rc").trimmed().to_string(), "1.2-rc");
+    /// ```
+    pub fn trimmed(&self) -> Version {
+        let mut version = self.clone();
+        let len = version.components.len();
+        while version.components.len() > 1 && version.components.last().is_some_and(|c| c == "0") {
+            version.components.pop();
+        }
+        if version.components.len() < len {
+            version.original = None;
+        }
+        version
+    }
+    /// compares like [`Ord`], but splits each main component into runs of digits and non-digits
+    /// and compares them run by run, like Debian does ("5djf" < "10djf").
+    ///
+    /// digit runs are compared numerically and are lower than non-digit runs, which are compared
+    /// lexically. if all shared runs are equal, the component with fewer runs is lower.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version1 = Version::parse("1.5djf");
+    /// let version2 = Version::parse("1.10djf");
+    /// assert_eq!(version1.cmp_segmented(&version2), Ordering::Less);
+    /// assert_eq!(version1.cmp(&version2), Ordering::Greater);
+    /// ```
+    pub fn cmp_segmented(&self, other: &Version) -> Ordering {
+        self.cmp_epoch(other)
+            .then_with(|| self.cmp_components_by(other, cmp_segments))
+            .then_with(|| self.cmp_pre_release(other))
+            .then_with(|| self.cmp_revision(other))
+            .then_with(|| self.cmp_build_metadata(other))
+    }
+    /// whether this version is compatible with `base` like in Cargo's `^base`: it is not lower,
+    /// and the leftmost non-zero component of `base` is the same (^1.2.3 means <2.0.0, and
+    /// ^0.2.3 means <0.3.0).
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let base = Version::parse("1.2.3");
+    /// assert!(Version::parse("1.5.0").satisfies_caret(&base));
+    /// assert!(!Version::parse("2.0.0").satisfies_caret(&base));
+    /// ```
+    pub fn satisfies_caret(&self, base: &Version) -> bool {
+        self.cmp_precedence(base) != Ordering::Less
+            && base.caret_upper().is_some_and(|upper| self.cmp_precedence(&upper) == Ordering::Less)
+    }
+    /// whether this version matches `base` like in Cargo's `~base`: it is not lower, and only
+    /// the patch changed if `base` has a minor component (~1.2.3 means <1.3.0), or only the minor
+    /// component otherwise (~1 means <2.0.0).
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let base = Version::parse("1.2.3");
+    /// assert!(Version::parse("1.2.9").satisfies_tilde(&base));
+    /// assert!(!Version::parse("1.3.0").satisfies_tilde(&base));
+    /// ```
+    pub fn satisfies_tilde(&self, base: &Version) -> bool {
+        self.cmp_precedence(base) != Ordering::Less
+            && base.tilde_upper().is_some_and(|upper| self.cmp_precedence(&upper) == Ordering::Less)
+    }
+    /// the lowest version that is too new for `^self`
+    pub(crate) fn caret_upper(&self) -> Option<Version> {
+        let specified = self.components.len().clamp(1, 3);
+        let index = (0..specified)
+            .find(|&i| self.numeric_component(i) != Some(0))
+            .unwrap_or(specified - 1);
+        self.bump(index)
+    }
+    /// the lowest version that is too new for `~self`
+    pub(crate) fn tilde_upper(&self) -> Option<Version> {
+        self.bump(self.components.len().clamp(1, 2) - 1)
+    }
+    /// the exact string this version was parsed from, or `None` if it was not parsed.
+    ///
+    /// [`Display`] writes a version in its usual form instead, so this is the way to get back
+    /// separators such as `_` or dropped empty components.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1_2_3");
+    /// assert_eq!(version.to_string(), "1.2.3");
+    /// assert_eq!(version.original(), Some("1_2_3"));
+    /// ```
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+    /// compares like [`Ord`], but only if every main component of both versions is a number.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::{CompareError, Version};
+    /// let version = Version::parse("1.2.3");
+    /// assert_eq!(version.try_cmp(&Version::parse("1.10")), Ok(Ordering::Less));
+    /// let error = CompareError::NonNumericComponent("sjf".to_string());
+    /// assert_eq!(version.try_cmp(&Version::parse("2.sjf.5djf")), Err(error));
+    /// ```
+    pub fn try_cmp(&self, other: &Version) -> Result<Ordering, CompareError> {
+        let non_numeric = self
+            .iter_components()
+            .chain(other.iter_components())
+            .find(|component| !is_number(component));
+        match non_numeric {
+            Some(component) => Err(CompareError::NonNumericComponent(component.to_string())),
+            None => Ok(self.cmp(other)),
+        }
+    }
+    /// the kind of this version.
+    ///
+    /// versions of different schemes are still ordered ("2023.03.01" > "1.2.3" because 2023 > 1),
+    /// but that order is not meaningful. [`Version::try_cmp_same_scheme`] refuses to compare them.
+    /// # Example
+    /// ```
+    /// use h_version::{Version, VersionScheme};
+    /// assert_eq!(Version::parse("1.2.3").scheme(), VersionScheme::SemVer);
+    /// assert_eq!(Version::parse("2023.03.01").scheme(), VersionScheme::CalVer);
+    /// assert_eq!(Version::parse("2.sjf.5djf").scheme(), VersionScheme::Unknown);
+    /// ```
+    pub fn scheme(&self) -> VersionScheme {
+        if self.looks_like_calver() {
+            VersionScheme::CalVer
+        } else if !self.components.is_empty() && self.iter_components().all(is_number) {
+            VersionScheme::SemVer
+        } else {
+            VersionScheme::Unknown
+        }
+    }
+    /// compares like [`Ord`], but only if both versions have the same [`Version::scheme`].
+    /// # Example
+    /// ```
+    /// use h_version::{CompareError, Version, VersionScheme};
+    /// let version = Version::parse("1.2.3");
+    /// let error = CompareError::DifferentSchemes(VersionScheme::SemVer, VersionScheme::CalVer);
+    /// assert_eq!(version.try_cmp_same_scheme(&Version::parse("2023.03.01")), Err(error));
+    /// ```
+    pub fn try_cmp_same_scheme(&self, other: &Version) -> Result<Ordering, CompareError> {
+        let (scheme, other_scheme) = (self.scheme(), other.scheme());
+        if scheme == other_scheme {
+            Ok(self.cmp(other))
+        } else {
+            Err(CompareError::DifferentSchemes(scheme, other_scheme))
+        }
+    }
+    /// compares like [`Ord`], but only if both versions have the same [`Version::scheme`], like
+    /// [`Version::try_cmp_same_scheme`]. [`PartialOrd`] still compares any two versions.
     /// # Example
     /// ```
     /// use h_version::Version;
-    /// let version = Version::parse("1:23423.553.845-rc+255");
-    /// let version = version.to_string();
-    /// assert_eq!(version,"1:23423.553.845-rc+255".to_string());
-    pub fn parse(version_str: &str) -> Self {
-        // Handle epochs
-        let mut parts = version_str.splitn(2, ':');
-        let epoch = parts.next().and_then(|s| s.parse::<u64>().ok());
-        let rest = parts.next().unwrap_or(version_str);
-
-        // Split into main version and build metadata
-        let mut parts = rest.splitn(2, '+');
-        let version_part = parts.next().unwrap_or(rest);
-        let build_metadata = parts.next().map(|s| s.to_string());
+    /// let version = Version::parse("2023.03.01");
+    /// assert_eq!(version.partial_cmp_scheme_aware(&Version::parse("1.2.3")), None);
+    /// ```
+    pub fn partial_cmp_scheme_aware(&self, other: &Version) -> Option<Ordering> {
+        self.try_cmp_same_scheme(other).ok()
+    }
+    /// the most significant part in which this version differs from `other`.
+    ///
+    /// parts are compared like in [`Ord`], so "1.2" and "1.2.0" are [`VersionDiff::Equal`].
+    /// # Example
+    /// ```
+    /// use h_version::{Version, VersionDiff};
+    /// let version = Version::parse("1.2.3");
+    /// assert_eq!(version.diff(&Version::parse("1.2.4")), VersionDiff::Patch);
+    /// assert_eq!(version.diff(&Version::parse("1.3.0")), VersionDiff::Minor);
+    /// ```
+    pub fn diff(&self, other: &Version) -> VersionDiff {
+        if self.cmp_epoch(other).is_ne() {
+            return VersionDiff::Epoch;
+        }
+        let len = self.components.len().max(other.components.len());
+        for i in 0..len {
+            let a = self.components.get(i).map_or("0", |s| s.as_str());
+            let b = other.components.get(i).map_or("0", |s| s.as_str());
+            if cmp_component(a, b).is_ne() {
+                return match i {
+                    0 => VersionDiff::Major,
+                    1 => VersionDiff::Minor,
+                    2 => VersionDiff::Patch,
+                    _ => VersionDiff::Extra,
+                };
+            }
+        }
+        if self.cmp_pre_release(other).is_ne() {
+            VersionDiff::PreRelease
+        } else if self.cmp_revision(other).is_ne() {
+            VersionDiff::Revision
+        } else if self.cmp_build_metadata(other).is_ne() {
+            VersionDiff::Build
+        } else {
+            VersionDiff::Equal
+        }
+    }
+    /// whether the main components match a pattern in which `*` matches any component
+    /// (e.g. "1.2.*"). the epoch, pre-release and build metadata are not matched.
+    ///
+    /// a `*` at the end of the pattern also matches any number of further components, so "1.*"
+    /// matches "1.2.3". without it, components missing from the pattern count as "0", so "1.2"
+    /// matches "1.2.0" but not "1.2.3".
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::parse("1.2.3");
+    /// assert!(version.matches_wildcard("1.2.*"));
+    /// assert!(version.matches_wildcard("1.*.3"));
+    /// assert!(!version.matches_wildcard("1.3.*"));
+    /// ```
+    pub fn matches_wildcard(&self, pattern: &str) -> bool {
+        let pattern: Vec<&str> = pattern.split(['.', '_']).collect();
+        let trailing_wildcard = pattern.last() == Some(&"*");
+        let len = self.components.len().max(pattern.len());
+        (0..len).all(|i| {
+            let component = self.components.get(i).map_or("0", |s| s.as_str());
+            match pattern.get(i) {
+                Some(&"*") => true,
+                Some(expected) => cmp_component(component, expected).is_eq(),
+                None => trailing_wildcard || cmp_component(component, "0").is_eq(),
+            }
+        })
+    }
+    /// whether this version and `other` are API compatible by SemVer rules: up to and including
+    /// their leftmost non-zero component, they are the same. so every minor change of a `0.y.z`
+    /// version is a breaking change.
+    ///
+    /// versions with different epochs, or with a non-numeric component before their leftmost
+    /// non-zero one, are never compatible.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert!(Version::parse("1.5.0").is_compatible_with(&Version::parse("1.2.0")));
+    /// assert!(!Version::parse("2.0.0").is_compatible_with(&Version::parse("1.2.0")));
+    /// assert!(!Version::parse("0.1.0").is_compatible_with(&Version::parse("0.2.0")));
+    /// ```
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        if self.cmp_epoch(other).is_ne() {
+            return false;
+        }
+        for i in 0..3 {
+            let a = self.components.get(i).map_or(Some(0), |_| self.numeric_component(i));
+            let b = other.components.get(i).map_or(Some(0), |_| other.numeric_component(i));
+            match (a, b) {
+                (Some(a), Some(b)) if a == b => {
+                    if a != 0 {
+                        return true;
+                    }
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+    /// a string that is the same exactly for versions that are equal (`==`), so it can be used
+    /// to deduplicate them: a zero epoch and trailing zero components are left out, leading
+    /// zeros of numbers (also in "devN" and "postN") and pre-release identifiers are removed and
+    /// the pre-release is lowercased.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("0:1.02.3-RC").to_canonical_string(), "1.2.3-rc");
+    /// assert_eq!(Version::parse("1.2.0.0").to_canonical_string(), "1.2");
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        let mut components: Vec<String> =
+            self.iter_components().map(|component| Component::new(component).to_canonical()).collect();
+        while components.len() > 1 && components.last().is_some_and(|component| component == "0") {
+            components.pop();
+        }
+        let mut version = Version::new(
+            self.epoch.filter(|&epoch| epoch != 0),
+            components,
+            self.pre_release.as_ref().map(|pre_release| {
+                let pre_release: String = pre_release.chars().flat_map(char::to_lowercase).collect();
+                pre_release.split('.').map(strip_leading_zeros).collect::<Vec<_>>().join(".")
+            }),
+            self.build_metadata.clone(),
+        );
+        version.tilde = self.tilde;
+        version.revision = self.revision;
+        version.to_string()
+    }
+    /// compares by SemVer precedence (see [`Version::cmp_precedence`]), then by build metadata
+    /// identifier by identifier, like pre-releases ("+2" < "+10" and "+1" < "+exp"). a version
+    /// without build metadata is lower.
+    ///
+    /// [`Ord`] compares build metadata as plain strings instead.
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use h_version::Version;
+    /// let version1 = Version::parse("1.0.0+2");
+    /// let version2 = Version::parse("1.0.0+10");
+    /// assert_eq!(version1.cmp_with_build(&version2), Ordering::Less);
+    /// ```
+    pub fn cmp_with_build(&self, other: &Version) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| match (&self.build_metadata, &other.build_metadata) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+                (Some(a), Some(b)) => cmp_identifiers(a, b),
+            })
+    }
+    /// replaces the main component at `index`.
+    ///
+    /// fails if there is no component at `index`, or if the new component is empty or contains a
+    /// character that is not allowed in a component (`.`, `_`, `-`, `~`, `+` or `:`).
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let mut version = Version::parse("1.2.3");
+    /// version.set_component(1, "5").unwrap();
+    /// assert_eq!(version.to_string(), "1.5.3");
+    /// assert!(version.set_component(3, "0").is_err());
+    /// ```
+    pub fn set_component(&mut self, index: usize, value: impl Into<String>) -> Result<(), &'static str> {
+        let value = value.into();
+        if value.is_empty() {
+            return Err("component is empty");
+        }
+        if has_separator(&value) {
+            return Err("component contains a separator");
+        }
+        let component = self.components.get_mut(index).ok_or("component index out of range")?;
+        *component = value;
+        self.original = None;
+        Ok(())
+    }
+    /// a key that orders like this version, but is much cheaper to compare, see [`VersionKey`].
+    pub fn sort_key(&self) -> VersionKey {
+        VersionKey::new(
+            self.epoch,
+            &self.components,
+            self.pre_release.as_deref(),
+            self.tilde,
+            self.revision,
+            self.build_metadata.as_deref(),
+        )
+    }
+    /// makes a `major.minor.patch` version from any str, as well as possible: the first (up to)
+    /// three numbers separated by `.` are kept, missing ones are "0" and everything else is
+    /// dropped. a str without any digit gives "0.0.0".
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::coerce("v1.2").to_string(), "1.2.0");
+    /// assert_eq!(Version::coerce("release-2023.03-final").to_string(), "2023.3.0");
+    /// ```
+    pub fn coerce(input: &str) -> Version {
+        let mut components = Vec::new();
+        let mut rest = input.find(|c: char| c.is_ascii_digit()).map_or("", |start| &input[start..]);
+        while components.len() < 3 {
+            let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+            if end == 0 {
+                break;
+            }
+            components.push(strip_leading_zeros(&rest[..end]).to_string());
+            rest = match rest[end..].strip_prefix('.') {
+                Some(tail) => tail,
+                None => break,
+            };
+        }
+        components.resize(3, "0".to_string());
+        Version::new(None, components, None, None)
+    }
+    /// makes versions from a list separated by newlines or commas, with [`Version::parse`].
+    /// blank entries are skipped.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let versions = Version::parse_many("1.0.0, 1.1.0\n\n2.0.0\n");
+    /// assert_eq!(versions, [Version::parse("1.0.0"), Version::parse("1.1.0"), Version::parse("2.0.0")]);
+    /// ```
+    pub fn parse_many(input: &str) -> Vec<Version> {
+        list_entries(input).map(|(_, entry)| Version::parse(entry)).collect()
+    }
+    /// makes versions from a list like [`Version::parse_many`], but with [`FromStr`]. fails with
+    /// every invalid entry and its line number (starting at 1).
+    /// # Example
+    /// ```
+    /// use h_version::{ParseVersionError, Version};
+    /// let errors = Version::try_parse_many("1.0.0\n1..3").unwrap_err();
+    /// assert_eq!(errors, [(2, ParseVersionError::EmptyComponent)]);
+    /// ```
+    pub fn try_parse_many(input: &str) -> Result<Vec<Version>, Vec<(usize, ParseVersionError)>> {
+        let mut versions = Vec::new();
+        let mut errors = Vec::new();
+        for (line, entry) in list_entries(input) {
+            match entry.parse() {
+                Ok(version) => versions.push(version),
+                Err(error) => errors.push((line, error)),
+            }
+        }
+        if errors.is_empty() { Ok(versions) } else { Err(errors) }
+    }
+    /// this version with another pre-release. `None` or "" removes it.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::from_numbers(1, 2, 3).with_pre_release("rc").to_string(), "1.2.3-rc");
+    /// assert_eq!(Version::parse("1.2.3-rc").with_pre_release(None).to_string(), "1.2.3");
+    /// ```
+    pub fn with_pre_release<'a>(mut self, pre_release: impl Into<Option<&'a str>>) -> Version {
+        self.pre_release = pre_release.into().filter(|s| !s.is_empty()).map(|s| s.to_string());
+        self.tilde &= self.pre_release.is_some();
+        self.original = None;
+        self
+    }
+    /// this version with other build metadata. `None` or "" removes it.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::from_numbers(1, 2, 3).with_build_metadata("build.42");
+    /// assert_eq!(version.to_string(), "1.2.3+build.42");
+    /// ```
+    pub fn with_build_metadata<'a>(mut self, build_metadata: impl Into<Option<&'a str>>) -> Version {
+        self.build_metadata = build_metadata.into().filter(|s| !s.is_empty()).map(|s| s.to_string());
+        self.original = None;
+        self
+    }
+    /// makes a version from a git tag with [`Version::parse`]. the `v` or `V` prefix is kept for
+    /// [`Version::to_tag`].
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// let version = Version::from_tag("v1.2.3");
+    /// assert_eq!(version.to_string(), "1.2.3");
+    /// assert_eq!(version.to_tag(), "v1.2.3");
+    /// ```
+    pub fn from_tag(tag: &str) -> Version {
+        Version::parse(tag)
+    }
+    /// this version as a git tag, with its prefix if it was parsed with one (the same as `{:#}`).
+    pub fn to_tag(&self) -> String {
+        format!("{self:#}")
+    }
+    /// the identifiers of the build metadata (e.g. `["git", "abc123"]` for "1.0.0+git.abc123"),
+    /// or none if there is no build metadata.
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("1.0.0+git.abc123").build_metadata_identifiers(), ["git", "abc123"]);
+    /// ```
+    pub fn build_metadata_identifiers(&self) -> Vec<&str> {
+        self.build_metadata.as_deref().map_or_else(Vec::new, |build_metadata| build_metadata.split('.').collect())
+    }
+    /// the first build metadata identifier that is a `YYYYMMDDhhmmss` timestamp (e.g.
+    /// "1.0.0+20230301144500"), as a date and time.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use h_version::Version;
+    /// let timestamp = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap().and_hms_opt(14, 45, 0).unwrap();
+    /// assert_eq!(Version::parse("1.0.0+20230301144500").build_timestamp(), Some(timestamp));
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn build_timestamp(&self) -> Option<chrono::NaiveDateTime> {
+        self.build_metadata_identifiers().into_iter().find_map(|identifier| {
+            if identifier.len() != 14 || !is_number(identifier) {
+                return None;
+            }
+            let number = |range: core::ops::Range<usize>| identifier[range].parse::<u32>().ok();
+            let year = identifier[..4].parse().ok()?;
+            chrono::NaiveDate::from_ymd_opt(year, number(4..6)?, number(6..8)?)?
+                .and_hms_opt(number(8..10)?, number(10..12)?, number(12..14)?)
+        })
+    }
+    /// whether this version matches every requirement (true if there are none).
+    /// # Example
+    /// ```
+    /// use h_version::{Version, VersionReq};
+    /// let reqs = [VersionReq::parse(">=1.0.0").unwrap(), VersionReq::parse("<2.0.0").unwrap()];
+    /// assert!(Version::parse("1.5.0").matches_all(&reqs));
+    /// assert!(!Version::parse("2.1.0").matches_all(&reqs));
+    /// assert!(Version::parse("2.1.0").matches_any(&reqs));
+    /// ```
+    pub fn matches_all(&self, reqs: &[VersionReq]) -> bool {
+        reqs.iter().all(|req| req.matches(self))
+    }
+    /// whether this version matches at least one requirement (false if there are none).
+    pub fn matches_any(&self, reqs: &[VersionReq]) -> bool {
+        reqs.iter().any(|req| req.matches(self))
+    }
+    /// this version as a SemVer string, like [`Display`]. fails like [`Version::parse_semver`] if
+    /// this is not a canonical SemVer version (e.g. with an epoch or a `~` pre-release).
+    /// # Example
+    /// ```
+    /// use h_version::{ParseVersionError, Version};
+    /// assert_eq!(Version::parse("2.3.4-rc+5").to_semver_string(), Ok("2.3.4-rc+5".to_string()));
+    /// assert_eq!(Version::parse("1:2.3.4-rc+5").to_semver_string(), Err(ParseVersionError::HasEpoch));
+    /// ```
+    pub fn to_semver_string(&self) -> Result<String, ParseVersionError> {
+        self.check_semver()?;
+        Ok(self.to_string())
+    }
+    /// this version as a Debian string: like [`Display`], but the epoch is always written
+    /// ("0:" if there is none).
+    /// # Example
+    /// ```
+    /// use h_version::Version;
+    /// assert_eq!(Version::parse("2.3.4~rc").to_debian_string(), "0:2.3.4~rc");
+    /// ```
+    pub fn to_debian_string(&self) -> String {
+        let mut version = self.clone();
+        version.epoch = Some(self.epoch_or_zero());
+        version.to_string()
+    }
+}
+impl FromStr for Version {
+    type Err = ParseVersionError;
 
-        // Split into main version and pre-release
-        let mut parts = version_part.splitn(2, '-');
-        let main_version = parts.next().unwrap_or(version_part);
-        let pre_release = parts.next().map(|s| s.to_string());
+    /// makes a version from a str, reporting what is wrong with it instead of guessing.
+    /// # Example
+    /// ```
+    /// use h_version::{ParseVersionError, Version};
+    /// # fn main() -> Result<(), ParseVersionError> {
+    /// let version: Version = "1:2.3.4-rc+5".parse()?;
+    /// assert_eq!(version.epoch, Some(1));
+    /// assert_eq!("bad:1.2.3".parse::<Version>(), Err(ParseVersionError::BadEpoch("bad".to_string())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(version_str: &str) -> Result<Self, Self::Err> {
+        Version::parse_with(version_str, false, None)
+    }
+}
+impl TryFrom<&str> for Version {
+    type Error = ParseVersionError;
 
-        // Split main version into components
-        let components: Vec<String> = main_version
-            .split(['.']) // Split on `.`
-            .map(|s| s.to_string())
-            .collect();
+    fn try_from(version_str: &str) -> Result<Self, Self::Error> {
+        version_str.parse()
+    }
+}
+impl TryFrom<String> for Version {
+    type Error = ParseVersionError;
 
-        Version {
-            epoch,
-            components,
-            pre_release,
-            build_metadata,
-        }
+    fn try_from(version_str: String) -> Result<Self, Self::Error> {
+        version_str.parse()
+    }
+}
+/// makes a `major.minor.patch` version, see [`Version::from_numbers`].
+/// # Example
+/// ```
+/// use h_version::Version;
+/// assert_eq!(Version::from((1, 2, 3)).to_string(), "1.2.3");
+/// ```
+impl From<(u64, u64, u64)> for Version {
+    fn from((major, minor, patch): (u64, u64, u64)) -> Self {
+        Version::from_numbers(major, minor, patch)
+    }
+}
+/// makes a `major.minor.patch` version, see [`Version::from_numbers`].
+/// # Example
+/// ```
+/// use h_version::Version;
+/// assert_eq!(Version::from([1, 2, 3]).to_string(), "1.2.3");
+/// ```
+impl From<[u64; 3]> for Version {
+    fn from([major, minor, patch]: [u64; 3]) -> Self {
+        Version::from_numbers(major, minor, patch)
     }
 }
+// `eq` is `cmp`, so that they can not disagree ("1.2" == "1.2.0")
 impl PartialEq for Version {
     fn eq(&self, other: &Self) -> bool {
-        self.epoch == other.epoch
-            && self.components == other.components
-            && self.pre_release == other.pre_release
-            && self.build_metadata == other.build_metadata
-    }
-    fn ne(&self, other: &Self) -> bool {
-        self.epoch != other.epoch
-            || self.components != other.components
-            || self.pre_release != other.pre_release
-            || self.build_metadata != other.build_metadata
+        self.cmp(other) == Ordering::Equal
     }
 }
 impl Eq for Version {}
 
+impl Hash for Version {
+    // versions that are equal have equal sort keys
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sort_key().hash(state);
+    }
+}
+
+// comparisons with version strings parse the string (with `Version::parse`) every time, so
+// they allocate. parse once and compare versions when comparing many times.
+impl PartialEq<str> for Version {
+    fn eq(&self, other: &str) -> bool {
+        self.eq_str(other)
+    }
+}
+impl PartialEq<&str> for Version {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+impl PartialEq<Version> for str {
+    fn eq(&self, other: &Version) -> bool {
+        other.eq_str(self)
+    }
+}
+impl PartialEq<Version> for &str {
+    fn eq(&self, other: &Version) -> bool {
+        other.eq_str(self)
+    }
+}
+impl PartialOrd<str> for Version {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+impl PartialOrd<&str> for Version {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+impl PartialOrd<Version> for str {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+impl PartialOrd<Version> for &str {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
 impl PartialOrd for Version {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -79,92 +1491,373 @@ impl PartialOrd for Version {
 }
 impl Ord for Version {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Compare epochs
-        if let Some(epoch_cmp) = self.epoch.partial_cmp(&other.epoch) {
-            if epoch_cmp != Ordering::Equal {
-                return epoch_cmp;
-            }
-        }
-
-        // Compare main components
-        for (a, b) in self.components.iter().zip(&other.components) {
-            let a_num = a.parse::<u64>().ok();
-            let b_num = b.parse::<u64>().ok();
-
-            match (a_num, b_num) {
-                // Numeric comparison
-                (Some(a_num), Some(b_num)) => {
-                    let cmp = a_num.cmp(&b_num);
-                    if cmp != Ordering::Equal {
-                        return cmp;
-                    }
-                }
-                // If one is numeric and the other is not, the numeric one is smaller
-                (Some(_), None) => return Ordering::Less,
-                (None, Some(_)) => return Ordering::Greater,
-                // comparison for non-numeric components
-                (None, None) => {
-                    let cmp = a.cmp(b);
-                    if cmp != Ordering::Equal {
-                        return cmp;
-                    }
-                }
-            }
-        }
-        // If main components are equal, compare pre-releases
-        if self.pre_release.is_some() || other.pre_release.is_some() {
-            return match (&self.pre_release, &other.pre_release) {
-                (None, None) => Ordering::Equal,
-                (None, Some(_)) => Ordering::Greater, // No pre-release is greater
-                (Some(_), None) => Ordering::Less, // Pre-release is less
-                (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()), // Compare pre-releases (because alpha, beta and rc are in order there is no need to compare them one by one. just compare the strings of them.)
-            }
-        }
-        // compare build metadata
-        self.build_metadata.partial_cmp(&other.build_metadata).unwrap()
+        self.cmp_precedence(other)
+            .then_with(|| self.cmp_build_metadata(other))
     }
 }
 impl Debug for Version {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let epoch = self.epoch.unwrap_or_default();
         let components = &self.components;
         let pre_release = self.pre_release.clone().unwrap_or_default();
-        let build_metadata = self.epoch.unwrap_or_default();
-        write!(f, "epoch:{epoch} components:{components:?} pre_release:{pre_release} build_metadata:{build_metadata}")
+        let build_metadata = self.build_metadata.clone().unwrap_or_default();
+        write!(f, "epoch:{epoch} components:{components:?} pre_release:{pre_release} build_metadata:{build_metadata}")?;
+        if self.tilde {
+            write!(f, " tilde:true")?;
+        }
+        if let Some(revision) = self.revision {
+            write!(f, " revision:{revision}")?;
+        }
+        Ok(())
     }
 }
 impl Display for Version {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut string = String::new();
+        // prefix, only in the alternate form (`{:#}`)
+        if let Some(prefix) = self.prefix.filter(|_| f.alternate()) {
+            string.push(prefix);
+        }
         // epoch
         let epoch = self.epoch;
         if let Some(epoch) = epoch {
             string += epoch.to_string().as_str();
             string += ":";
         }
-        // components
-        let components = &self.components;
-        for component in components {
-            string += component.as_str();
-            string += ".";
+        // components, only the first `precision` ones (`{:.2}`)
+        let precision = f.precision();
+        let len = precision.unwrap_or(self.components.len()).min(self.components.len());
+        string += self.components[..len].join(".").as_str();
+        if precision.is_none() {
+            // pre_release
+            let pre_release = self.pre_release.clone();
+            if let Some(pre_release) = pre_release {
+                string += if self.tilde { "~" } else { "-" };
+                string += pre_release.as_str();
+            }
+            let build_metadata = self.build_metadata.clone();
+            if let Some(build_metadata) = build_metadata {
+                string += "+";
+                string += build_metadata.as_str();
+            }
+            // the Debian revision ends the version, even after build metadata ("1.2+dfsg-1")
+            if let Some(revision) = self.revision {
+                string += "-";
+                string += revision.to_string().as_str();
+            }
         }
-        string.remove(string.len() - 1);
-        // pre_release
-        let pre_release = self.pre_release.clone();
-        if let Some(pre_release) = pre_release {
-            string += "-";
-            string += pre_release.as_str();
+        // padding (`{:>10}`), not `Formatter::pad` as it would also cut the string to `precision`
+        let padding = f.width().unwrap_or(0).saturating_sub(string.chars().count());
+        let (before, after) = match f.align() {
+            Some(core::fmt::Alignment::Right) => (padding, 0),
+            Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            write!(f, "{fill}")?;
         }
-        let build_metadata = self.build_metadata.clone();
-        if let Some(build_metadata) = build_metadata {
-            string += "+";
-            string += build_metadata.as_str();
+        write!(f, "{string}")?;
+        for _ in 0..after {
+            write!(f, "{fill}")?;
         }
-        write!(f, "{}",string)
+        Ok(())
     }
 }
+/// the default version is "0.0.1", the first version of a new project. it is kept for
+/// backwards compatibility, [`Version::parse`] returns it for invalid strings. see also
+/// [`Version::zero`].
 impl Default for Version {
     fn default() -> Self {
-        Version::parse("0.0.1")
+        Version::from_numbers(0, 0, 1)
+    }
+}
+
+/// compares two version strings like [`Version::parse`] and [`Ord`] would, but without making
+/// [`Version`]s, so nothing is allocated.
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use h_version::cmp_raw;
+/// assert_eq!(cmp_raw("1.2.3", "1.10.0"), Ordering::Less);
+/// assert_eq!(cmp_raw("v1.2", "1.2.0"), Ordering::Equal);
+/// ```
+pub fn cmp_raw(a: &str, b: &str) -> Ordering {
+    let (a, b) = (lenient_tokens(a), lenient_tokens(b));
+    let epoch = |tokens: &Tokens| tokens.epoch.map_or(0, |epoch| epoch.parse::<u64>().unwrap_or(0));
+    epoch(&a)
+        .cmp(&epoch(&b))
+        .then_with(|| {
+            // like `Version::cmp_components`, without the empty components `Version::parse` drops
+            let mut a_components = a.components().filter(|component| !component.is_empty());
+            let mut b_components = b.components().filter(|component| !component.is_empty());
+            loop {
+                let cmp = match (a_components.next(), b_components.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (a, b) => cmp_component(a.unwrap_or("0"), b.unwrap_or("0")),
+                };
+                if cmp != Ordering::Equal {
+                    return cmp;
+                }
+            }
+        })
+        .then_with(|| match (a.pre_release, b.pre_release) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a_pre), Some(b_pre)) => b.tilde.cmp(&a.tilde).then_with(|| cmp_identifiers(a_pre, b_pre)),
+        })
+        .then_with(|| a.build_metadata.cmp(&b.build_metadata))
+}
+
+/// the non-blank entries of a list separated by newlines or commas, with their line numbers.
+fn list_entries(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().flat_map(|(i, line)| {
+        line.split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(move |entry| (i + 1, entry))
+    })
+}
+
+/// compares two pre-releases identifier by identifier (e.g. "alpha.2" < "alpha.10").
+///
+/// "SNAPSHOT" identifiers (ignoring case) are the lowest. numeric identifiers are compared
+/// numerically ("01" is 1) and are always lower than other alphanumeric ones, which are compared
+/// lexically (ignoring case). if all shared identifiers are equal, the pre-release with fewer
+/// identifiers is lower.
+fn cmp_identifiers(a: &str, b: &str) -> Ordering {
+    cmp_identifiers_by(a, b, cmp_identifier)
+}
+fn cmp_identifiers_by(a: &str, b: &str, identifier_cmp: impl Fn(&str, &str) -> Ordering) -> Ordering {
+    let mut a_identifiers = a.split('.');
+    let mut b_identifiers = b.split('.');
+    loop {
+        let (a, b) = match (a_identifiers.next(), b_identifiers.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => (a, b),
+        };
+        let cmp = identifier_cmp(a, b);
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+    }
+}
+
+/// compares two pre-release identifiers, see [`cmp_identifiers`].
+fn cmp_identifier(a: &str, b: &str) -> Ordering {
+    match (is_snapshot(a), is_snapshot(b)) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
+    match (is_number(a), is_number(b)) {
+        (true, true) => cmp_digits(a, b),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        // compared char by char, not with `str::to_lowercase`, so nothing is allocated
+        (false, false) => a.chars().flat_map(char::to_lowercase).cmp(b.chars().flat_map(char::to_lowercase)),
+    }
+}
+
+/// the rank of the leading letters of an identifier, and what follows them ("m1" is "m" and "1").
+fn tag_rank<'a>(identifier: &'a str, ranks: &[(&str, u32)]) -> Option<(u32, &'a str)> {
+    let end = identifier.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(identifier.len());
+    let (tag, rest) = identifier.split_at(end);
+    ranks
+        .iter()
+        .find(|(name, _)| !tag.is_empty() && name.eq_ignore_ascii_case(tag))
+        .map(|&(_, rank)| (rank, rest))
+}
+
+/// compares two pre-release identifiers with a rank table, see [`Version::cmp_ranked`].
+fn cmp_ranked_identifier(a: &str, b: &str, ranks: &[(&str, u32)]) -> Ordering {
+    match (tag_rank(a, ranks), tag_rank(b, ranks)) {
+        (Some((a_rank, a_rest)), Some((b_rank, b_rest))) => {
+            a_rank.cmp(&b_rank).then_with(|| cmp_segments(a_rest, b_rest))
+        }
+        // ranked tags come after numbers (and unranked snapshots, which `cmp_identifier` puts
+        // below numbers) and before unknown tags
+        (Some(_), None) if is_number(b) || is_snapshot(b) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) if is_number(a) || is_snapshot(a) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => cmp_identifier(a, b),
+    }
+}
+
+/// compares two main components.
+///
+/// "devN" components are lower than everything else, then come numeric components, then
+/// "postN" components, and then all other ones. "devN", numbers and "postN" are compared
+/// numerically (even if they do not fit in a `u64`), other components lexically.
+pub(crate) fn cmp_component(a: &str, b: &str) -> Ordering {
+    // fast path for the common case of two numbers, it agrees with the order of `Component`
+    if is_number(a) && is_number(b) {
+        return cmp_digits(a, b);
+    }
+    Component::new(a).cmp(&Component::new(b))
+}
+
+/// a main component, the variants are in order. the digits of `Dev`, `BigNumeric` and `Post`
+/// have no leading zeros, so equal numbers have equal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Component<'a> {
+    Dev(&'a str),        // "dev4" (PEP 440), the digits
+    Numeric(u128),       // "4"
+    BigNumeric(&'a str), // a number that does not fit in a `u128`, the digits
+    Post(&'a str),       // "post4" (PEP 440), the digits
+    Alpha(&'a str),      // "x", compared lexically
+}
+impl<'a> Component<'a> {
+    pub(crate) fn new(component: &'a str) -> Self {
+        if let Some(number) = pep440_number(component, "dev") {
+            Component::Dev(number.trim_start_matches('0'))
+        } else if is_number(component) {
+            match component.parse() {
+                Ok(number) => Component::Numeric(number),
+                Err(_) => Component::BigNumeric(component.trim_start_matches('0')),
+            }
+        } else if let Some(number) = pep440_number(component, "post") {
+            Component::Post(number.trim_start_matches('0'))
+        } else {
+            Component::Alpha(component)
+        }
+    }
+    /// the component as it is written by [`Version::to_canonical_string`], equal components give
+    /// the same string ("dev01" and "dev1" give "dev1").
+    fn to_canonical(self) -> String {
+        let number = |digits: &str| if digits.is_empty() { "0".to_string() } else { digits.to_string() };
+        match self {
+            Component::Dev(digits) => format!("dev{}", number(digits)),
+            Component::Numeric(number) => number.to_string(),
+            Component::BigNumeric(digits) => digits.to_string(),
+            Component::Post(digits) => format!("post{}", number(digits)),
+            Component::Alpha(component) => component.to_string(),
+        }
+    }
+    fn rank(&self) -> u8 {
+        match self {
+            Component::Dev(_) => 0,
+            Component::Numeric(_) => 1,
+            Component::BigNumeric(_) => 2,
+            Component::Post(_) => 3,
+            Component::Alpha(_) => 4,
+        }
+    }
+}
+impl PartialOrd for Component<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Component<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Component::Numeric(a), Component::Numeric(b)) => a.cmp(b),
+            (Component::Dev(a), Component::Dev(b))
+            | (Component::BigNumeric(a), Component::BigNumeric(b))
+            | (Component::Post(a), Component::Post(b)) => cmp_digits(a, b),
+            (Component::Alpha(a), Component::Alpha(b)) => a.cmp(b),
+            // every `Numeric` fits in a `u128` and no `BigNumeric` does, so the rank decides
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+/// compares two components run by run, see [`Version::cmp_segmented`].
+fn cmp_segments(a: &str, b: &str) -> Ordering {
+    let mut a_segments = segments(a);
+    let mut b_segments = segments(b);
+    loop {
+        let (a, b) = match (a_segments.next(), b_segments.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => (a, b),
+        };
+        let a_digits = a.starts_with(|c: char| c.is_ascii_digit());
+        let b_digits = b.starts_with(|c: char| c.is_ascii_digit());
+        let cmp = match (a_digits, b_digits) {
+            (true, true) => cmp_digits(a, b),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => a.cmp(b),
+        };
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+    }
+}
+
+/// splits a str into runs of ASCII digits and non-digits ("5djf" gives "5" and "djf").
+fn segments(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    core::iter::from_fn(move || {
+        let digits = rest.starts_with(|c: char| c.is_ascii_digit());
+        let end = rest.find(|c: char| c.is_ascii_digit() != digits).unwrap_or(rest.len());
+        let (segment, tail) = rest.split_at(end);
+        rest = tail;
+        (!segment.is_empty()).then_some(segment)
+    })
+}
+
+/// compares two strings of ASCII digits by their value, whatever their length.
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// the number of a PEP 440 component like "dev4" or "post1" ("dev" alone is "dev0").
+fn pep440_number<'a>(component: &'a str, kind: &str) -> Option<&'a str> {
+    component
+        .strip_prefix(kind)
+        .filter(|number| number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// whether a pre-release identifier is "SNAPSHOT", ignoring case.
+pub(crate) fn is_snapshot(identifier: &str) -> bool {
+    identifier.eq_ignore_ascii_case("snapshot")
+}
+
+/// whether a str is made of ASCII digits only, however many.
+pub(crate) fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// fails with the first identifier of a SemVer pre-release or build metadata that is empty or
+/// has a character other than `[0-9A-Za-z-]`.
+fn check_semver_identifiers(part: &str) -> Result<(), ParseVersionError> {
+    let valid = |identifier: &str| {
+        !identifier.is_empty() && identifier.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    };
+    match part.split('.').find(|identifier| !valid(identifier)) {
+        Some(identifier) => Err(ParseVersionError::NotSemVer(identifier.to_string())),
+        None => Ok(()),
     }
-}
\ No newline at end of file
+}
+
+/// whether a str contains a character that separates the parts of a version, so it can not be a
+/// main component.
+pub(crate) fn has_separator(s: &str) -> bool {
+    s.contains(['.', '_', '-', '~', '+', ':'])
+}
+
+/// whether a str is a number written with leading zeros (e.g. "01", but not "0" or "0a").
+fn has_leading_zero(s: &str) -> bool {
+    s.len() > 1 && s.starts_with('0') && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// removes the leading zeros of a number ("007" gives "7" and "000" gives "0"). other strs are
+/// returned as they are.
+fn strip_leading_zeros(s: &str) -> &str {
+    if has_leading_zero(s) {
+        let stripped = s.trim_start_matches('0');
+        if stripped.is_empty() { "0" } else { stripped }
+    } else {
+        s
+    }
+}
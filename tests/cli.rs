@@ -0,0 +1,38 @@
+// the command line tool needs the `std` feature
+#![cfg(feature = "std")]
+use std::process::{Command, Output};
+
+fn h_version(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_h-version")).args(args).output().unwrap()
+}
+
+#[test]
+fn compares_versions() {
+    let output = h_version(&["1.2.3", "1.10.0"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "1.2.3 is less than 1.10.0\n");
+    let output = h_version(&["--cmp", "2.0.0", "1.0.0"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "1\n");
+}
+
+#[test]
+fn rejects_invalid_versions() {
+    let output = h_version(&["1.2.3", "1..3"]);
+    assert_eq!(output.status.code(), Some(65));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "invalid version \"1..3\": version has an empty component\n");
+    assert!(output.stdout.is_empty());
+    let output = h_version(&["--cmp", "bad:1.2.3", "1.0.0"]);
+    assert_eq!(output.status.code(), Some(65));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "invalid version \"bad:1.2.3\": invalid epoch \"bad\"\n");
+    let output = h_version(&["--satisfies", "^1.0", ""]);
+    assert_eq!(output.status.code(), Some(65));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "invalid version \"\": version string is empty\n");
+}
+
+#[test]
+fn checks_requirements() {
+    assert_eq!(h_version(&["--satisfies", "^1.0", "1.5.0"]).status.code(), Some(0));
+    assert_eq!(h_version(&["--satisfies", "^1.0", "2.0.0"]).status.code(), Some(1));
+    assert_eq!(h_version(&["--satisfies", ">=", "2.0.0"]).status.code(), Some(65));
+    assert_eq!(h_version(&["1.0.0"]).status.code(), Some(64));
+}
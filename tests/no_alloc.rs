@@ -0,0 +1,35 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use h_version::cmp_raw;
+
+// counts the allocations of this test binary, which has a single test
+struct CountingAllocator;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn cmp_raw_does_not_allocate() {
+    let pairs = [
+        ("1.0.0-alpha.beta", "1.0.0-alpha.rc"),
+        ("v1:2.3.4~RC.1+build", "1:2.3.4~rc.2"),
+        ("1.2.dev3", "1.2.post3"),
+        ("1.0.0-SNAPSHOT", "1.0.0-Alpha"),
+        ("123456789012345678901234567890123456789012.0", "2.x"),
+        ("not a version", ""),
+    ];
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    for (a, b) in pairs {
+        std::hint::black_box(cmp_raw(std::hint::black_box(a), std::hint::black_box(b)));
+    }
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+}